@@ -0,0 +1,66 @@
+//! compares the seeded deserialization of list responses with the deserialization into an
+//! intermediate `Vec<RawOrder>`
+//!
+//! run with `cargo bench --features test`
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+use comdirect_api::bench::{orders_intermediate, orders_seeded};
+use comdirect_api::types::deposit::ComdirectDeposit;
+
+const ORDERS: usize = 1000;
+
+fn deposit() -> ComdirectDeposit {
+    serde_json::from_str(r#"{"depotId":"D0000000000000000000000000000001","depotDisplayId":"1234567"}"#).unwrap()
+}
+
+fn orders_json(count: usize) -> Vec<u8> {
+    let orders: Vec<String> = (0..count)
+        .map(|i| format!(
+            r#"{{
+                "depotId":"D0000000000000000000000000000001",
+                "orderId":"O{:031}",
+                "instrumentId":"US0079031078",
+                "orderType":"LIMIT",
+                "side":"BUY",
+                "orderStatus":"OPEN",
+                "validityType":"GFD",
+                "limit":{{"value":"{}.5","unit":"EUR"}},
+                "creationTimestamp":"2020-08-03T10:22:03,123456+02",
+                "quantity":{{"value":"10","unit":"XXX"}},
+                "openQuantity":{{"value":"10","unit":"XXX"}},
+                "executions":[]
+            }}"#,
+            i, i % 100,
+        ))
+        .collect();
+
+    format!(
+        r#"{{"paging":{{"index":0,"matches":{}}},"values":[{}]}}"#,
+        count,
+        orders.join(","),
+    ).into_bytes()
+}
+
+#[bench]
+fn orders_seeded_deserialization(b: &mut Bencher) {
+    let deposit = deposit();
+    let json = orders_json(ORDERS);
+    assert_eq!(orders_seeded(&json, &deposit).unwrap().len(), ORDERS);
+
+    b.bytes = json.len() as u64;
+    b.iter(|| orders_seeded(&json, &deposit));
+}
+
+#[bench]
+fn orders_intermediate_deserialization(b: &mut Bencher) {
+    let deposit = deposit();
+    let json = orders_json(ORDERS);
+    assert_eq!(orders_intermediate(&json, &deposit).unwrap().len(), ORDERS);
+
+    b.bytes = json.len() as u64;
+    b.iter(|| orders_intermediate(&json, &deposit));
+}
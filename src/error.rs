@@ -21,6 +21,12 @@ pub enum Error {
     UnprocessableRequest,
 
     NotSupported,
+    /// a value of the versioned JSON format was written by a different version of this crate
+    UnsupportedSchemaVersion,
+    /// comdirect does not support orders with an infinite validity
+    UnsupportedValidity,
+    /// a value was combined with a deposit, that it does not belong to
+    DifferentDeposit,
     IOError,
 
     Other,
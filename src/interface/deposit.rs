@@ -14,16 +14,23 @@ impl ApiClient {
         )
     }
 
-    //noinspection RsUnresolvedReference
     pub fn get_positions<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<Vec<Position<'d>>> {
-        let raw_positions = self.send_get_positions_request(deposit)?;
-        let mut positions = Vec::with_capacity(raw_positions.len());
+        let response = self.send_get_positions_request::<IgnoredAny>(deposit, false)?;
+        Ok(response.values)
+    }
 
-        for raw in raw_positions {
-            positions.push(Position::from_raw(raw, deposit));
-        }
+    /// like [`get_positions`](ApiClient::get_positions), but every position also carries the
+    /// full [`Instrument`] data (name, ISIN, instrument type, ...)
+    pub fn get_positions_with_instruments<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<Vec<Position<'d>>> {
+        let response = self.send_get_positions_request::<IgnoredAny>(deposit, true)?;
+        Ok(response.values)
+    }
 
-        Ok(positions)
+    /// returns the depot totals (value, profit/loss, ...) together with all positions
+    pub fn get_depot_overview<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<DepotOverview<'d>> {
+        let response = self.send_get_positions_request::<DepotSummary>(deposit, false)?;
+        let summary = response.aggregated.ok_or(Error::UnexpectedJsonValues)?;
+        Ok(DepotOverview::new(summary, response.values))
     }
 
     pub fn get_position<'d>(&self, deposit: &'d ComdirectDeposit, position_id: &PositionId) -> Result<Position<'d>> {
@@ -39,26 +46,183 @@ impl ApiClient {
         Ok(())
     }
 
+    pub fn get_positions_owned(&self, deposit: &Arc<ComdirectDeposit>) -> Result<Vec<OwnedPosition>> {
+        self.get_positions(deposit)?
+            .into_iter()
+            .map(|p| OwnedPosition::with_deposit(p, Arc::clone(deposit)))
+            .collect()
+    }
+
+    pub fn update_owned_position(&self, position: &mut OwnedPosition) -> Result<()> {
+        let mut borrowed = position.to_borrowed();
+        self.update_position(&mut borrowed)?;
+        let raw = borrowed.into_raw();
+        position.set_raw(raw);
+        Ok(())
+    }
+
+    /// Refreshes all given positions with a single request per deposit.
+    ///
+    /// The positions are matched by their [`PositionId`]. Positions, that were opened since the
+    /// last fetch, and the ids of positions, that were closed since, are returned as [`PositionChanges`].
+    /// The closed positions are kept unchanged in `positions`.
+    pub fn update_positions<'d>(&self, positions: &mut [Position<'d>]) -> Result<PositionChanges<'d>> {
+        let mut deposits: Vec<&'d ComdirectDeposit> = Vec::new();
+        for position in positions.iter() {
+            if !deposits.iter().any(|d| d.id() == position.deposit().id()) {
+                deposits.push(*position.deposit());
+            }
+        }
+
+        let mut changes = PositionChanges::default();
+        for deposit in deposits {
+            self.update_deposit_positions(deposit, positions, false, &mut changes)?;
+        }
+
+        Ok(changes)
+    }
+
+    /// loads all positions (with their instruments) and all open orders of the deposit
+    pub fn load_deposit<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<ManagedDeposit<'d>> {
+        let positions = self.send_get_positions_request::<IgnoredAny>(deposit, true)?.values;
+        let orders = self.get_open_orders(deposit)?;
+        Ok(ManagedDeposit::new(
+            DepositPositions::new(deposit, positions),
+            DepositOrders::new(deposit, orders),
+        ))
+    }
+
+    /// refreshes the positions and the open orders of the deposit
+    pub fn refresh_deposit<'d>(&self, managed_deposit: &mut ManagedDeposit<'d>) -> Result<PositionChanges<'d>> {
+        let (positions, orders) = managed_deposit.split_mut();
+        let changes = self.refresh_deposit_positions(positions)?;
+        self.refresh_deposit_orders(orders)?;
+        Ok(changes)
+    }
+
+    /// Updates the existing positions in place, adds new positions and removes closed positions.
+    /// The returned changes describe the added and removed positions.
+    pub fn refresh_deposit_positions<'d>(&self, positions: &mut DepositPositions<'d>) -> Result<PositionChanges<'d>> {
+        let mut changes = PositionChanges::default();
+        self.update_deposit_positions(*positions.deposit(), positions.as_mut_slice(), true, &mut changes)?;
+        positions.apply_changes(&changes);
+        Ok(changes)
+    }
+
+    /// Updates the open orders in place, adds new open orders and removes orders,
+    /// that are not open anymore.
+    pub fn refresh_deposit_orders<'d>(&self, orders: &mut DepositOrders<'d>) -> Result<()> {
+        let open_orders = self.get_open_orders(*orders.deposit())?;
+        orders.replace(open_orders);
+        Ok(())
+    }
+
+    /// all orders, that still have open parts, i.e. partially executed or pending orders
+    #[inline(always)]
+    fn get_open_orders<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<Vec<Order<'d>>> {
+        let mut orders = self.get_orders(deposit)?;
+        orders.retain(|order| !is_final(order.status()));
+        Ok(orders)
+    }
+
+    fn update_deposit_positions<'d>(&self, deposit: &'d ComdirectDeposit, positions: &mut [Position<'d>], with_instruments: bool, changes: &mut PositionChanges<'d>)
+        -> Result<()> {
+        let values = self
+            .send_get_positions_request_raw(deposit, with_instruments)?
+            .json::<JsonResponseValues<serde_json::Value>>()?
+            .values;
+
+        let mut updated = Vec::with_capacity(values.len());
+        for value in values {
+            let id = value
+                .get("positionId")
+                .and_then(serde_json::Value::as_str)
+                .ok_or(Error::UnexpectedJsonValues)?;
+            let position = positions
+                .iter_mut()
+                .find(|p| p.deposit().id() == deposit.id() && p.raw().id().as_str() == id);
+
+            match position {
+                Some(position) => {
+                    updated.push(position.raw().id().clone());
+                    position.deserialize(&value)?;
+                }
+                None => {
+                    let raw_position = serde_json::from_value::<RawPosition>(value)?;
+                    changes.added.push(Position::from_raw(raw_position, deposit));
+                }
+            }
+        }
+
+        changes.closed.extend(
+            positions
+                .iter()
+                .filter(|p| p.deposit().id() == deposit.id() && !updated.contains(p.raw().id()))
+                .map(|p| p.raw().id().clone())
+        );
+
+        Ok(())
+    }
+
     pub fn get_deposit_transactions<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<Vec<Transaction<'d>>> {
-        self._get_deposit_transactions(deposit, None)
+        self._iter_deposit_transactions(deposit, None, false).collect()
     }
 
     pub fn get_deposit_transactions_filtered<'d>(&self, deposit: &'d ComdirectDeposit, filter_parameters: &TransactionFilterParameters) -> Result<Vec<Transaction<'d>>> {
-        self._get_deposit_transactions(deposit, Some(filter_parameters))
+        self._iter_deposit_transactions(deposit, Some(filter_parameters), false).collect()
+    }
+
+    /// like [`get_deposit_transactions_filtered`](ApiClient::get_deposit_transactions_filtered),
+    /// but every transaction also carries the full [`Instrument`] data
+    pub fn get_deposit_transactions_with_instruments<'d>(&self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&TransactionFilterParameters>)
+        -> Result<Vec<Transaction<'d>>> {
+        self._iter_deposit_transactions(deposit, filter_parameters, true).collect()
+    }
+
+    pub fn get_deposit_transactions_owned(&self, deposit: &Arc<ComdirectDeposit>, filter_parameters: Option<&TransactionFilterParameters>)
+        -> Result<Vec<OwnedTransaction>> {
+        self._iter_deposit_transactions(deposit, filter_parameters, false)
+            .map(|t| OwnedTransaction::with_deposit(t?, Arc::clone(deposit)))
+            .collect()
+    }
+
+    /// lazily iterates over all transactions of the deposit, requesting one page at a time
+    pub fn iter_deposit_transactions<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit) -> PageIter<'c, Transaction<'d>> {
+        self._iter_deposit_transactions(deposit, None, false)
+    }
+
+    pub fn iter_deposit_transactions_filtered<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit, filter_parameters: &'c TransactionFilterParameters)
+        -> PageIter<'c, Transaction<'d>> {
+        self._iter_deposit_transactions(deposit, Some(filter_parameters), false)
+    }
+
+    pub fn iter_deposit_transactions_with_instruments<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&'c TransactionFilterParameters>)
+        -> PageIter<'c, Transaction<'d>> {
+        self._iter_deposit_transactions(deposit, filter_parameters, true)
     }
 
     #[inline(always)]
-    fn send_get_positions_request(&self, deposit: &ComdirectDeposit) -> Result<Vec<RawPosition>> {
+    fn send_get_positions_request<'d, A: DeserializeOwned>(&self, deposit: &'d ComdirectDeposit, with_instruments: bool)
+        -> Result<DepositResponse<Position<'d>, A>> {
+        let response = self.send_get_positions_request_raw(deposit, with_instruments)?;
+        Self::deserialize_deposit_response(response, deposit)
+    }
+
+    #[inline(always)]
+    fn send_get_positions_request_raw(&self, deposit: &ComdirectDeposit, with_instruments: bool) -> Result<Response> {
         let session = session_is_active!(self.session);
         let url = format!("{}/{}/positions", url!("/brokerage/v3/depots"), deposit.id());
 
+        let mut request = self.make_get_session_request(&url, session)
+            .query(&[("without-attr", "depot")]);
+        if with_instruments {
+            request = request.query(&[("with-attr", "instrument")]);
+        }
+
         Ok(
-            self.make_get_session_request(&url, session)
-                .query(&[("without-attr", "depot")])
+            request
                 .send()?
                 .error_for_status()?
-                .json::<JsonResponseValues<RawPosition>>()?
-                .values
         )
     }
 
@@ -77,33 +241,33 @@ impl ApiClient {
 
     //noinspection RsUnresolvedReference
     #[inline(always)]
-    fn _get_deposit_transactions<'d>(&self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&TransactionFilterParameters>) -> Result<Vec<Transaction<'d>>> {
-        let raw_transactions = self.send_get_deposit_transactions_request(deposit, filter_parameters)?;
-        let mut transactions = Vec::with_capacity(raw_transactions.len());
-
-        for raw in raw_transactions {
-            transactions.push(Transaction::from_raw(raw, deposit))
+    fn _iter_deposit_transactions<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&'c TransactionFilterParameters>, with_instruments: bool)
+        -> PageIter<'c, Transaction<'d>> {
+        let pages = PageIter::new(move |paging_parameters| {
+            self.send_get_deposit_transactions_request(deposit, filter_parameters, paging_parameters, with_instruments)
+        });
+        // the lower booking date bound is applied to the received pages, so the paging is unaffected
+        match filter_parameters {
+            Some(filters) => pages.retain(move |transaction| filters.matches_min_booking_date(transaction)),
+            None => pages
         }
-
-        Ok(transactions)
     }
 
     #[inline(always)]
-    fn send_get_deposit_transactions_request(&self, deposit: &ComdirectDeposit, filter_parameters: Option<&TransactionFilterParameters>) -> Result<Vec<RawTransaction>> {
+    fn send_get_deposit_transactions_request<'d>(&self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&TransactionFilterParameters>, paging_parameters: PagingParameters, with_instruments: bool)
+        -> Result<(Paging, Vec<Transaction<'d>>)> {
         let session = session_is_active!(self.session);
         let url = format!("{}/{}/transactions", url!("/brokerage/v3/depots"), deposit.id());
 
-        let mut request = self.make_get_session_request(&url, session)
-            .query(&[("without-attr", "instrument")]);
+        let mut request = self.make_get_session_request(&url, session);
+        // the instrument is part of every transaction by default
+        if !with_instruments {
+            request = request.query(&[("without-attr", "instrument")]);
+        }
         if let Some(filters) = filter_parameters {
             request = request.query(filters);
         }
 
-        Ok(
-            request.send()?
-                .error_for_status()?
-                .json::<JsonResponseValues<RawTransaction>>()?
-                .values
-        )
+        Self::fetch_deposit_page(request, paging_parameters, deposit)
     }
 }
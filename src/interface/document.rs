@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use super::*;
+
+impl ApiClient {
+    pub fn get_documents(&self) -> Result<Vec<Document>> {
+        self.iter_documents().collect()
+    }
+
+    /// lazily iterates over all documents of the PostBox, requesting one page at a time
+    pub fn iter_documents(&self) -> PageIter<Document> {
+        const URL: &str = url!("/messages/clients/user/v2/documents");
+
+        PageIter::new(move |paging_parameters| {
+            let session = session_is_active!(self.session);
+            Self::fetch_page(self.make_get_session_request(URL, session), paging_parameters)
+        })
+    }
+
+    /// Downloads the content of a document.
+    /// Be aware, that comdirect marks unread documents as read, once they are downloaded.
+    pub fn download_document(&self, document: &Document) -> Result<Vec<u8>> {
+        let session = session_is_active!(self.session);
+        let url = format!("{}/{}", url!("/messages/v2/documents"), document.id().as_str());
+
+        Ok(
+            self.make_get_session_request(&url, session)
+                .header(ACCEPT, document.mime_type().as_str())
+                .send()?
+                .error_for_status()?
+                .bytes()?
+                .to_vec()
+        )
+    }
+
+    /// Downloads every document of the PostBox, that is not yet stored in `dir`.
+    ///
+    /// Every document is stored as `<date>_<category>_<name>_<id>.<ext>` together with a JSON
+    /// sidecar containing its metadata. Already archived documents are tracked in a
+    /// [`PostBoxManifest`], so consecutive runs only download new documents.
+    /// Returns the newly archived documents.
+    pub fn archive_postbox<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<Document>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        let mut manifest = PostBoxManifest::load(dir)?;
+        let mut archived = Vec::new();
+
+        for document in self.iter_documents() {
+            let document = document?;
+            if manifest.contains(document.id()) {
+                continue;
+            }
+
+            let content = self.download_document(&document)?;
+            manifest.archive(dir, &document, &content)?;
+            // the manifest is saved after every document, so an interrupted run does not
+            // cause documents to be downloaded twice
+            manifest.save(dir)?;
+
+            archived.push(document);
+        }
+
+        Ok(archived)
+    }
+}
@@ -1,7 +1,47 @@
 use super::*;
 
 impl ApiClient {
+    /// Returns the instrument of the derivative.
+    /// The instrument is only requested, if it is not in the instrument cache yet.
     pub fn get_instrument(&self, derivative: &Derivative) -> Result<Instrument> {
+        if let Some(instrument) = self.with_instrument_cache(|cache| cache.get(derivative).cloned()) {
+            return Ok(instrument);
+        }
+
+        let instrument = self.fetch_instrument(derivative)?;
+        self.with_instrument_cache(|cache| cache.insert(instrument.clone()));
+        Ok(instrument)
+    }
+
+    /// Returns the instruments of all derivatives in the same order.
+    ///
+    /// The derivatives are resolved one after another with `get_instrument`,
+    /// so there is one request per instrument, that is not in the instrument cache yet.
+    /// The first failing request aborts the resolution.
+    pub fn resolve_instruments(&self, derivatives: &[Derivative]) -> Result<Vec<Instrument>> {
+        derivatives
+            .iter()
+            .map(|derivative| self.get_instrument(derivative))
+            .collect()
+    }
+
+    /// Gives access to the instrument cache, i.e. to save it on disk or to clear it.
+    ///
+    /// The cache is locked while `f` runs, so `f` must not request instruments itself.
+    pub fn with_instrument_cache<R>(&self, f: impl FnOnce(&mut InstrumentCache) -> R) -> R {
+        // the cache is never left in an inconsistent state, so a poisoned lock can be ignored
+        let mut cache = self.instrument_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut cache)
+    }
+
+    /// replaces the instrument cache, i.e. with one that was loaded from disk
+    pub fn set_instrument_cache(&mut self, cache: InstrumentCache) {
+        *self.instrument_cache.get_mut().unwrap_or_else(|poisoned| poisoned.into_inner()) = cache;
+    }
+
+    fn fetch_instrument(&self, derivative: &Derivative) -> Result<Instrument> {
         let session = session_is_active!(self.session);
         let url = format!("{}/{}", url!("/brokerage/v1/instruments/"), derivative.as_ref());
 
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 use std::result::Result as StdResult;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
 
 use chrono::Local;
 use rand::{self, Rng};
@@ -7,24 +10,34 @@ use reqwest::blocking::{Client, RequestBuilder, Response};
 use reqwest::header::{ACCEPT, CONTENT_TYPE};
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::IntoUrl;
+use serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny};
 use serde::Serialize;
 use wall_street::derivative::Derivative;
 
 use crate::error::Error;
 use crate::serde::{JsonResponseValue, JsonResponseValues};
+use crate::serde::deposit_values::{DepositResponse, DepositValues, FromDepositRaw};
 use crate::session::{GrantType, PreSession, Session, SessionId, SessionStatus};
 use crate::session::tan::{TanChallenge, TanChallengeType};
 use crate::types::cost_indication::{ChangeCostIndication, CostIndication, RawCostIndication};
-use crate::types::deposit::ComdirectDeposit;
+use crate::types::deposit::{ComdirectDeposit, DepositOrders, DepositPositions, DepotOverview, DepotSummary, ManagedDeposit};
+use crate::types::document::Document;
+use crate::types::document::archive::PostBoxManifest;
 use crate::types::instrument::Instrument;
+use crate::types::instrument::cache::InstrumentCache;
 use crate::types::market_place::{JsonResponseMarketplaces, MarketPlace, MarketPlaceFilterParameters};
 use crate::types::order::{Order, OrderFilterParameters, OrderId, RawOrder};
 use crate::types::order::order_change::{DeleteOrder, OrderChange, OrderChangeAction, OrderChangeValidation};
-use crate::types::order::order_outline::OrderOutline;
-use crate::types::position::{Position, PositionId, RawPosition};
-use crate::types::quote::{Quote, QuoteOutline, QuoteTicket, RawQuote};
+use crate::types::order::fill::{is_final, OrderFill};
+use crate::types::order::order_outline::{OrderOutline, OwnedOrderOutline};
+use crate::types::order::prepared::{PreparedOrder, PreparedOrderChange, PreparedOrderDeletion, PreparedQuoteOrder};
+use crate::types::order::watcher::OrderWatcher;
+use crate::types::owned::{OwnedOrder, OwnedPosition, OwnedQuote, OwnedTransaction};
+use crate::types::paging::{PageIter, Paging, PagingParameters};
+use crate::types::position::{Position, PositionChanges, PositionId, RawPosition};
+use crate::types::quote::{OwnedQuoteOutline, Quote, QuoteOutline, QuoteTicket, RawQuote};
 use crate::types::quote::order_outline::QuoteOrderOutline;
-use crate::types::transaction::{RawTransaction, Transaction, TransactionFilterParameters};
+use crate::types::transaction::{Transaction, TransactionFilterParameters};
 
 const HEX_CHARSET: &[u8] = b"0123456789abcdef";
 
@@ -49,10 +62,11 @@ macro_rules! session_is_active {
     };
 }
 
-macro_rules! tan_is_free {
-    ($tan:expr) => {
-        if *$tan.typ() != TanChallengeType::Free {
-            return Err(Error::UnexpectedTanType);
+/// comdirect only accepts validities, that can be converted into a `GFD` or `GTD` validity
+macro_rules! validity_is_supported {
+    ($outline:expr) => {
+        if $outline.has_infinite_validity() {
+            return Err(Error::UnsupportedValidity);
         }
     };
 }
@@ -71,6 +85,7 @@ macro_rules! session_request_method {
 
 pub mod session;
 pub mod deposit;
+pub mod document;
 pub mod instrument;
 pub mod order;
 pub mod quote;
@@ -94,6 +109,7 @@ pub struct ApiClient {
 
     client: Client,
     session: Option<Session>,
+    instrument_cache: Mutex<InstrumentCache>,
 }
 
 impl Drop for ApiClient {
@@ -111,6 +127,7 @@ impl ApiClient {
             password,
             client: Self::default_client(),
             session: None,
+            instrument_cache: Mutex::new(InstrumentCache::default()),
         }
     }
 
@@ -160,6 +177,44 @@ impl ApiClient {
         ("x-once-authentication-info", format!(r#"{{"id":"{}"}}"#, tan_challenge.id().as_str()))
     }
 
+    #[inline(always)]
+    fn fetch_page<V: DeserializeOwned>(request: RequestBuilder, paging_parameters: PagingParameters)
+        -> Result<(Paging, Vec<V>)> {
+        let json = request
+            .query(&paging_parameters)
+            .send()?
+            .error_for_status()?
+            .json::<JsonResponseValues<V>>()?;
+        Ok((json.paging, json.values))
+    }
+
+    #[inline(always)]
+    fn fetch_deposit_page<'d, T: FromDepositRaw<'d>>(request: RequestBuilder, paging_parameters: PagingParameters, deposit: &'d ComdirectDeposit)
+        -> Result<(Paging, Vec<T>)> {
+        let response = request
+            .query(&paging_parameters)
+            .send()?
+            .error_for_status()?;
+        Self::deserialize_deposit_values(response, deposit)
+    }
+
+    #[inline(always)]
+    fn deserialize_deposit_values<'d, T: FromDepositRaw<'d>>(response: Response, deposit: &'d ComdirectDeposit)
+        -> Result<(Paging, Vec<T>)> {
+        let response = Self::deserialize_deposit_response::<T, IgnoredAny>(response, deposit)?;
+        Ok((response.paging, response.values))
+    }
+
+    #[inline(always)]
+    fn deserialize_deposit_response<'d, T: FromDepositRaw<'d>, A: DeserializeOwned>(response: Response, deposit: &'d ComdirectDeposit)
+        -> Result<DepositResponse<T, A>> {
+        let body = response.bytes()?;
+        let mut deserializer = serde_json::Deserializer::from_slice(&body);
+        let response = DepositValues::<T, A>::new(deposit).deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(response)
+    }
+
     session_request_method!(make_get_session_request, get);
     session_request_method!(make_post_session_request, post);
     session_request_method!(make_patch_session_request, patch);
@@ -29,37 +29,53 @@ impl ApiClient {
     }
 
     pub fn get_orders<'d>(&self, deposit: &'d ComdirectDeposit) -> Result<Vec<Order<'d>>> {
-        self._get_orders(deposit, None)
+        self._iter_orders(deposit, None).collect()
     }
 
     pub fn get_orders_filtered<'d>(&self, deposit: &'d ComdirectDeposit, filter_parameters: &OrderFilterParameters)
         -> Result<Vec<Order<'d>>> {
-        self._get_orders(deposit, Some(filter_parameters))
+        self._iter_orders(deposit, Some(filter_parameters)).collect()
+    }
+
+    pub fn get_orders_owned(&self, deposit: &Arc<ComdirectDeposit>, filter_parameters: Option<&OrderFilterParameters>)
+        -> Result<Vec<OwnedOrder>> {
+        self._iter_orders(deposit, filter_parameters)
+            .map(|o| OwnedOrder::with_deposit(o?, Arc::clone(deposit)))
+            .collect()
+    }
+
+    /// Creates a watcher, that polls the order books of the deposits and emits an event
+    /// for every change.
+    pub fn watch_orders<'c, 'd>(&'c self, deposits: Vec<&'d ComdirectDeposit>) -> OrderWatcher<'c, 'd> {
+        OrderWatcher::new(self, deposits)
+    }
+
+    /// lazily iterates over all orders of the order book, requesting one page at a time
+    pub fn iter_orders<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit) -> PageIter<'c, Order<'d>> {
+        self._iter_orders(deposit, None)
+    }
+
+    pub fn iter_orders_filtered<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit, filter_parameters: &'c OrderFilterParameters)
+        -> PageIter<'c, Order<'d>> {
+        self._iter_orders(deposit, Some(filter_parameters))
     }
 
     //noinspection RsUnresolvedReference
     #[inline(always)]
-    fn _get_orders<'d>(&self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&OrderFilterParameters>)
-        -> Result<Vec<Order<'d>>> {
-        let session = session_is_active!(&self.session);
+    fn _iter_orders<'c, 'd: 'c>(&'c self, deposit: &'d ComdirectDeposit, filter_parameters: Option<&'c OrderFilterParameters>)
+        -> PageIter<'c, Order<'d>> {
         let url = format!("{}/{}/v3/orders", url!("/brokerage/depots"), deposit.id());
 
-        let mut request = self.make_get_session_request(&url, session);
-        if let Some(filters) = filter_parameters {
-            request = request.query(filters);
-        }
-
-        let response = request.send()?
-            .error_for_status()?;
-
-        let json = response.json::<JsonResponseValues<RawOrder>>()?;
+        PageIter::new(move |paging_parameters| {
+            let session = session_is_active!(self.session);
 
-        let mut orders = Vec::with_capacity(json.values.len());
-        for raw in json.values {
-            orders.push(Order::from_raw(raw, deposit));
-        }
+            let mut request = self.make_get_session_request(&url, session);
+            if let Some(filters) = filter_parameters {
+                request = request.query(filters);
+            }
 
-        Ok(orders)
+            Self::fetch_deposit_page(request, paging_parameters, deposit)
+        })
     }
 
     pub fn get_order<'d>(&self, deposit: &'d ComdirectDeposit, order_id: &OrderId) -> Result<Order<'d>> {
@@ -74,13 +90,49 @@ impl ApiClient {
         Ok(Order::from_raw(raw, deposit))
     }
 
+    /// Refreshes the order every `poll_interval`, until it is executed, canceled or expired,
+    /// or until the `timeout` is reached.
+    /// If `cancel_remainder` is set, the order is deleted after the timeout, so no
+    /// further executions can happen.
+    pub fn wait_for_order<'d>(&self, order: &Order<'d>, timeout: Duration, poll_interval: Duration, cancel_remainder: bool)
+        -> Result<OrderFill<'d>> {
+        let deadline = Instant::now() + timeout;
+        let deposit = *order.deposit();
+
+        loop {
+            let current = self.get_order(deposit, order.id())?;
+            if is_final(current.status()) {
+                return Ok(OrderFill::new(current, false, false));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                if !cancel_remainder {
+                    return Ok(OrderFill::new(current, true, false));
+                }
+
+                self.delete_order(current).map_err(|(e, _)| e)?;
+                let current = self.get_order(deposit, order.id())?;
+                return Ok(OrderFill::new(current, true, true));
+            }
+
+            sleep(poll_interval.min(deadline - now));
+        }
+    }
+
+    pub fn get_order_owned(&self, deposit: &Arc<ComdirectDeposit>, order_id: &OrderId) -> Result<OwnedOrder> {
+        let order = self.get_order(deposit, order_id)?;
+        OwnedOrder::with_deposit(order, Arc::clone(deposit))
+    }
+
     pub fn order_cost_indication<'o, 'd, 'i, 'm>(&self, order_outline: &'o OrderOutline<'d, 'i, 'm>) -> Result<CostIndication<'o, 'd, 'i, 'm>> {
+        validity_is_supported!(order_outline);
         let raw = self._order_cost_indication(order_outline)?;
         let cost_indication = CostIndication::from_raw(raw, order_outline);
         Ok(cost_indication)
     }
 
-    pub(super) fn _order_cost_indication<O: Serialize>(&self, outline: &O) -> Result<RawCostIndication> {
+    pub(crate) fn _order_cost_indication<O: Serialize>(&self, outline: &O) -> Result<RawCostIndication> {
         const URL: &str = url!("/brokerage/v3/orders/costindicationexante");
         let session = session_is_active!(self.session);
 
@@ -95,6 +147,7 @@ impl ApiClient {
     }
 
     pub fn pre_validate_order_outline(&self, order_outline: &OrderOutline) -> Result<()> {
+        validity_is_supported!(order_outline);
         const URL: &str = url!("/brokerage/v3/orders/prevalidation");
         let session = session_is_active!(self.session);
 
@@ -107,27 +160,38 @@ impl ApiClient {
     }
 
     pub fn place_order<'d>(&self, order_outline: &OrderOutline<'d, '_, '_>) -> Result<Order<'d>> {
+        self.prepare_order(order_outline)?.commit()
+    }
+
+    /// Like [`place_order`](ApiClient::place_order), but the placed order shares the deposit
+    /// of the outline.
+    pub fn place_owned_order(&self, order_outline: &OwnedOrderOutline) -> Result<OwnedOrder> {
+        let order = self.place_order(&order_outline.to_borrowed())?;
+        OwnedOrder::with_deposit(order, Arc::clone(order_outline.deposit()))
+    }
+
+    /// Validates the order outline without placing it.
+    /// The order is placed with [`PreparedOrder::commit`].
+    pub fn prepare_order<'o, 'd, 'i, 'm>(&self, order_outline: &'o OrderOutline<'d, 'i, 'm>)
+        -> Result<PreparedOrder<'_, 'o, 'd, 'i, 'm>> {
+        validity_is_supported!(order_outline);
         let tan_challenge = self.validate_outline(order_outline)?;
-        let order = self.place_order_outline(order_outline, tan_challenge)?;
-        Ok(order)
+        Ok(PreparedOrder::new(self, order_outline, tan_challenge))
     }
 
     pub(super) fn validate_outline<O: Serialize>(&self, outline: &O) -> Result<TanChallenge> {
         const URL: &str = url!("/brokerage/v3/orders/validation");
         let session = session_is_active!(self.session);
 
-        let response = self.make_post_session_request(URL, session)
-            .json(outline)
-            .send()?
-            .error_for_status()?;
-
-        let tan_challenge = ApiClient::extract_tan_challenge(response.headers())?;
-        tan_is_free!(tan_challenge);
+        let (_, tan_challenge) = self.send_validation(|| {
+            self.make_post_session_request(URL, session)
+                .json(outline)
+        })?;
 
         Ok(tan_challenge)
     }
 
-    fn place_order_outline<'d>(&self, order_outline: &OrderOutline<'d, '_, '_>, tan_challenge: TanChallenge) -> Result<Order<'d>> {
+    pub(crate) fn place_order_outline<'d>(&self, order_outline: &OrderOutline<'d, '_, '_>, tan_challenge: TanChallenge) -> Result<Order<'d>> {
         let raw_order = self.place_outline(order_outline, tan_challenge)?;
         let order = Order::from_raw(raw_order, order_outline.deposit());
         Ok(order)
@@ -136,11 +200,10 @@ impl ApiClient {
     pub(super) fn place_outline<O: Serialize>(&self, outline: &O, tan_challenge: TanChallenge) -> Result<RawOrder> {
         const URL: &str = url!("/brokerage/v3/orders");
         let session = session_is_active!(self.session);
-        let tan_header = Self::make_x_authentication_info_header(&tan_challenge);
+        let request = self.make_post_session_request(URL, session)
+            .json(outline);
 
-        let response = self.make_post_session_request(URL, session)
-            .header(tan_header.0, tan_header.1)
-            .json(outline)
+        let response = Self::answer_tan_challenge(request, &tan_challenge)?
             .send()?
             .error_for_status()?;
 
@@ -149,6 +212,7 @@ impl ApiClient {
     }
 
     pub fn pre_validate_order_change(&self, order_change: &OrderChange) -> Result<()> {
+        validity_is_supported!(order_change);
         let validation = OrderChangeValidation::Change(order_change);
         self._pre_validate_order_change(validation)
     }
@@ -176,6 +240,7 @@ impl ApiClient {
     }
 
     pub fn order_change_cost_indication<'oc, 'o>(&self, order_change: &'oc OrderChange<'o>) -> Result<ChangeCostIndication<'oc, 'o, '_>> {
+        validity_is_supported!(order_change);
         let validation = OrderChangeValidation::Change(order_change);
         self._order_change_cost_indication(validation)
     }
@@ -188,7 +253,7 @@ impl ApiClient {
     //     self._order_change_cost_indication(validation)
     // }
     #[inline(always)]
-    fn _order_change_cost_indication<'oc, 'o, 'd>(&self, change_validation: OrderChangeValidation<'o, 'd, 'oc>) -> Result<ChangeCostIndication<'oc, 'o, 'd>> {
+    pub(crate) fn _order_change_cost_indication<'oc, 'o, 'd>(&self, change_validation: OrderChangeValidation<'o, 'd, 'oc>) -> Result<ChangeCostIndication<'oc, 'o, 'd>> {
         use OrderChangeValidation::*;
         let session = session_is_active!(self.session);
         let url = format!("{}/{}/costindicationexante", url!("/brokerage/v3/orders"), change_validation.order_id());
@@ -210,28 +275,38 @@ impl ApiClient {
     }
 
     pub fn change_order(&self, order_change: OrderChange) -> Result<()> {
+        self.prepare_order_change(order_change)?.commit()
+    }
+
+    /// Validates the order change without sending it.
+    /// The order is changed with [`PreparedOrderChange::commit`].
+    pub fn prepare_order_change<'o>(&self, order_change: OrderChange<'o>) -> Result<PreparedOrderChange<'_, 'o>> {
         let tan_challenge = self.validate_order_change(&order_change)?;
-        let action = OrderChangeAction::Change(order_change);
-        self._change_order(action, tan_challenge)
+        Ok(PreparedOrderChange::new(self, order_change, tan_challenge))
     }
 
     pub fn delete_order<'d>(&self, order: Order<'d>) -> StdResult<(), (Error, Order<'d>)> {
-        macro_rules! map_err {
-            ($expr:expr) => {
-                match $expr {
-                    Ok(o) => o,
-                    Err(e) => return Err((e, order))
-                }
-            };
+        self.prepare_order_deletion(order)?.commit()
+    }
+
+    /// Validates the deletion of the order without deleting it.
+    /// The order is deleted with [`PreparedOrderDeletion::commit`].
+    pub fn prepare_order_deletion<'d>(&self, order: Order<'d>) -> StdResult<PreparedOrderDeletion<'_, 'd>, (Error, Order<'d>)> {
+        match self.validate_order_deletion(&order) {
+            Ok(tan_challenge) => Ok(PreparedOrderDeletion::new(self, order, tan_challenge)),
+            Err(e) => Err((e, order))
         }
+    }
 
-        let tan_challenge = map_err!(self.validate_order_deletion(&order));
-        let action = OrderChangeAction::Delete(&order);
-        map_err!(self._change_order(action, tan_challenge));
-        Ok(())
+    pub fn delete_owned_order(&self, order: OwnedOrder) -> StdResult<(), (Error, OwnedOrder)> {
+        let result = self
+            .delete_order(order.to_borrowed())
+            .map_err(|(e, _)| e);
+        result.map_err(|e| (e, order))
     }
 
     fn validate_order_change(&self, order_change: &OrderChange) -> Result<TanChallenge> {
+        validity_is_supported!(order_change);
         let validation = OrderChangeValidation::Change(order_change);
         self._validate_order_change(validation)
     }
@@ -247,27 +322,22 @@ impl ApiClient {
         let session = session_is_active!(self.session);
         let url = format!("{}/{}/validation", url!("/brokerage/v3/orders"), change_validation.order_id());
 
-        let mut request = self.make_post_session_request(&url, session);
-        match change_validation {
-            Change(order_change) => request = request.json(order_change),
-            Delete(_) => request = request.json(&DeleteOrder {})
-        }
-
-        let response = request
-            .send()?
-            .error_for_status()?;
-        let tan_challenge = ApiClient::extract_tan_challenge(response.headers())?;
-        tan_is_free!(tan_challenge);
+        let (_, tan_challenge) = self.send_validation(|| {
+            let request = self.make_post_session_request(&url, session);
+            match change_validation {
+                Change(order_change) => request.json(order_change),
+                Delete(_) => request.json(&DeleteOrder {})
+            }
+        })?;
 
         Ok(tan_challenge)
     }
 
     #[inline(always)]
-    fn _change_order(&self, change_action: OrderChangeAction, tan_challenge: TanChallenge) -> Result<()> {
+    pub(crate) fn _change_order(&self, change_action: OrderChangeAction, tan_challenge: TanChallenge) -> Result<()> {
         use OrderChangeAction::*;
         let session = session_is_active!(self.session);
         let url = format!("{}/{}", url!("/brokerage/v3/orders"), change_action.order_id());
-        let tan_header = Self::make_x_authentication_info_header(&tan_challenge);
 
         let request = match change_action {
             Change(ref order_change) => {
@@ -282,8 +352,7 @@ impl ApiClient {
             }
         };
 
-        request
-            .header(tan_header.0, tan_header.1)
+        Self::answer_tan_challenge(request, &tan_challenge)?
             .send()?
             .error_for_status()?;
 
@@ -11,34 +11,42 @@ impl ApiClient {
         Ok(quote)
     }
 
+    /// Like [`get_quote`](ApiClient::get_quote), but the returned quote shares the deposit
+    /// of the outline.
+    pub fn get_quote_owned(&self, outline: &OwnedQuoteOutline) -> Result<OwnedQuote> {
+        let quote = self.get_quote(&outline.to_borrowed())?;
+        OwnedQuote::with_deposit(quote, Arc::clone(outline.deposit()))
+    }
+
+    pub fn place_owned_quote_order(&self, quote: &OwnedQuote) -> Result<OwnedOrder> {
+        let order = self.place_quote_order(quote.to_borrowed().into())?;
+        OwnedOrder::with_deposit(order, Arc::clone(quote.deposit()))
+    }
+
     fn validate_quote_outline(&self, outline: &QuoteOutline) -> Result<(QuoteTicket, TanChallenge)> {
         const URL: &str = url!("/brokerage/v3/quoteticket");
         let session = session_is_active!(self.session);
 
-        let response = self.make_post_session_request(URL, session)
-            .json(outline)
-            .send()?
-            .error_for_status()?;
+        let (response, tan_challenge) = self.send_validation(|| {
+            self.make_post_session_request(URL, session)
+                .json(outline)
+        })?;
 
-        let tan_challenge = ApiClient::extract_tan_challenge(response.headers())?;
         let quote_ticket = response.json::<QuoteTicket>()?;
 
         Ok((quote_ticket, tan_challenge))
     }
 
     fn validate_quote_tan(&self, quote_ticket: &QuoteTicket, tan_challenge: TanChallenge) -> Result<()> {
-        match tan_challenge.typ() {
-            TanChallengeType::Free => {}
-            _ => return Err(Error::UnexpectedTanType)
-        }
-
         let session = session_is_active!(self.session);
         let url = format!("{}/{}", url!("/brokerage/v3/quoteticket"), quote_ticket.id());
-        let tan_header = Self::make_x_authentication_info_header(&tan_challenge);
 
-        self.make_patch_session_request(&url, session)
-            .header("x-once-authentication", "TAN_FREI")
-            .header(tan_header.0, tan_header.1)
+        let mut request = self.make_patch_session_request(&url, session);
+        if *tan_challenge.typ() == TanChallengeType::Free {
+            request = request.header("x-once-authentication", "TAN_FREI");
+        }
+
+        Self::answer_tan_challenge(request, &tan_challenge)?
             .send()?
             .error_for_status()?;
 
@@ -68,12 +76,17 @@ impl ApiClient {
     }
 
     pub fn place_quote_order<'d>(&self, quote_order_outline: QuoteOrderOutline<'d>) -> Result<Order<'d>> {
+        self.prepare_quote_order(quote_order_outline)?.commit()
+    }
+
+    /// Validates the quote order without placing it.
+    /// The order is placed with [`PreparedQuoteOrder::commit`].
+    pub fn prepare_quote_order<'d>(&self, quote_order_outline: QuoteOrderOutline<'d>) -> Result<PreparedQuoteOrder<'_, 'd>> {
         let tan_challenge = self.validate_outline(&quote_order_outline)?;
-        let order = self.place_quote_order_outline(&quote_order_outline, tan_challenge)?;
-        Ok(order)
+        Ok(PreparedQuoteOrder::new(self, quote_order_outline, tan_challenge))
     }
 
-    fn place_quote_order_outline<'d>(&self, quote_order_outline: &QuoteOrderOutline<'d>, tan_challenge: TanChallenge) -> Result<Order<'d>> {
+    pub(crate) fn place_quote_order_outline<'d>(&self, quote_order_outline: &QuoteOrderOutline<'d>, tan_challenge: TanChallenge) -> Result<Order<'d>> {
         let raw_order = self.place_outline(quote_order_outline, tan_challenge)?;
         let order = Order::from_raw(raw_order, quote_order_outline.deposit());
         Ok(order)
@@ -133,7 +133,7 @@ impl ApiClient {
         let url = format!("{}/{}/validate", url!("/session/clients/user/v1/sessions"), session.session_uuid.as_str());
 
         let response = self
-            .make_request_tan_challenge_request_builder(url, session, desired_tan_type)
+            .make_request_tan_challenge_request_builder(url, session, desired_tan_type.as_ref())
             .send()?
             .error_for_status()?;
 
@@ -146,7 +146,7 @@ impl ApiClient {
         Ok(tan_challenge)
     }
 
-    fn make_request_tan_challenge_request_builder(&self, url: String, session: &Session, desired_tan_type: Option<TanChallengeType>)
+    fn make_request_tan_challenge_request_builder(&self, url: String, session: &Session, desired_tan_type: Option<&TanChallengeType>)
         -> RequestBuilder {
         let data = format!(
             r#"{{
@@ -197,6 +197,62 @@ impl ApiClient {
         }
     }
 
+    /// Sends the validation of an order action and returns the response with its TAN challenge.
+    /// Like while creating a session, a push TAN is requested instead, if comdirect chose a
+    /// TAN type, that can not be answered.
+    pub(super) fn send_validation<F>(&self, make_request: F) -> Result<(Response, TanChallenge)>
+        where F: Fn() -> RequestBuilder {
+        let response = make_request()
+            .send()?
+            .error_for_status()?;
+        let tan_challenge = Self::extract_tan_challenge(response.headers())?;
+
+        if Self::tan_type_is_supported(tan_challenge.typ()) {
+            return Ok((response, tan_challenge));
+        }
+        if !tan_challenge.available_types().contains(&TanChallengeType::PushTan) {
+            return Err(Error::UnsupportedTanType);
+        }
+
+        let response = make_request()
+            .header("x-once-authentication-info", TanChallengeType::PushTan.to_authentication_info())
+            .send()?
+            .error_for_status()?;
+        let tan_challenge = Self::extract_tan_challenge(response.headers())?;
+
+        match tan_challenge.typ() {
+            TanChallengeType::PushTan => Ok((response, tan_challenge)),
+            _ => Err(Error::UnexpectedTanType)
+        }
+    }
+
+    /// The photoTAN graphic can not be shown on the console, so photoTAN challenges are
+    /// answered with a push TAN instead.
+    #[inline(always)]
+    fn tan_type_is_supported(tan_type: &TanChallengeType) -> bool {
+        use TanChallengeType::*;
+        matches!(tan_type, Free | PushTan | MobileTan)
+    }
+
+    /// Adds the TAN challenge to the request of an order action.
+    /// Unless the TAN is free, the user is asked for the TAN the same way as while creating a session.
+    pub(super) fn answer_tan_challenge(request_builder: RequestBuilder, tan_challenge: &TanChallenge) -> Result<RequestBuilder> {
+        let tan_header = Self::make_x_authentication_info_header(tan_challenge);
+        let request_builder = request_builder.header(tan_header.0, tan_header.1);
+
+        match tan_challenge.typ() {
+            TanChallengeType::Free => Ok(request_builder),
+            TanChallengeType::PushTan => {
+                Self::ask_for_tan(tan_challenge)?;
+                Ok(request_builder)
+            }
+            _ => {
+                let tan = Self::ask_for_tan(tan_challenge)?;
+                Ok(request_builder.header("x-once-authentication", tan.trim()))
+            }
+        }
+    }
+
     fn activate_tan(&self, session: &Session, tan_challenge: TanChallenge) -> Result<()> {
         let tan = Self::ask_for_tan(&tan_challenge)?;
         let url = format!("{}/{}", url!("/session/clients/user/v1/sessions"), session.session_uuid.as_str());
@@ -259,7 +315,7 @@ impl ApiClient {
         use TanChallengeType::*;
         match tan_challenge.typ() {
             PushTan => print!("Please open your PhotoTan App and activate the PushTan."),
-            Free => unreachable!("got TanType Free while creating a session"),
+            Free => unreachable!("got TanType Free while asking for a TAN"),
             MobileTan if tan_challenge.challenge().is_some() => println!(
                 "Please call '{}' and input the TAN.",
                 tan_challenge.challenge().as_ref().unwrap()
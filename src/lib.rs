@@ -88,6 +88,63 @@ macro_rules! option_builder_fn {
     };
 }
 
+/// Creates an enum for values of a response, that falls back to `Unknown(String)` for values,
+/// which are unknown to this crate, instead of failing the whole response.
+/// Unknown values are logged, if enabled with [`log_unknown_values`].
+macro_rules! response_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $enum_:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        $vis enum $enum_ {
+            $($(#[$variant_meta])* $variant,)*
+            /// a value, that is unknown to this crate
+            Unknown(String),
+        }
+
+        impl $enum_ {
+            /// the value, as it is used by the comdirect API
+            #[allow(unused)]
+            $vis fn as_str(&self) -> &str {
+                match self {
+                    $($enum_::$variant => $value,)*
+                    $enum_::Unknown(value) => value.as_str(),
+                }
+            }
+
+            #[allow(unused)]
+            $vis fn is_unknown(&self) -> bool {
+                matches!(self, $enum_::Unknown(_))
+            }
+        }
+
+        impl ::serde::Serialize for $enum_ {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where S: ::serde::Serializer {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $enum_ {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, <D as ::serde::Deserializer<'de>>::Error>
+                where D: ::serde::Deserializer<'de> {
+                let value: String = ::serde::Deserialize::deserialize(deserializer)?;
+                Ok(match value.as_str() {
+                    $($value => $enum_::$variant,)*
+                    _ => {
+                        crate::serde::unknown::unknown_value(stringify!($enum_), &value);
+                        $enum_::Unknown(value)
+                    }
+                })
+            }
+        }
+    };
+}
+
 #[cfg(feature = "raw_interface")]
 pub mod interface;
 #[cfg(not(feature = "raw_interface"))]
@@ -99,3 +156,10 @@ pub mod error;
 mod session;
 #[doc(hidden)]
 mod serde;
+
+pub use crate::serde::unknown::log_unknown_values;
+pub use crate::session::tan::{TanChallenge, TanChallengeType};
+
+#[cfg(feature = "test")]
+#[doc(hidden)]
+pub use crate::serde::deposit_values::bench;
@@ -27,6 +27,23 @@ pub(crate) mod quantity {
         where D: Deserializer<'de> {
         Ok(AmountValue::deserialize(deserializer)?.value)
     }
+
+    pub(crate) mod option {
+        use super::*;
+
+        pub(crate) fn serialize<S>(value: &Option<F64>, serializer: S) -> Result<S::Ok, S::Error>
+            where S: Serializer {
+            match value {
+                Some(value) => super::serialize(value, serializer),
+                None => serializer.serialize_none()
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<F64>, D::Error>
+            where D: Deserializer<'de> {
+            Ok(Option::<AmountValue>::deserialize(deserializer)?.map(|amount| amount.value))
+        }
+    }
 }
 
 pub(crate) mod price {
@@ -67,3 +84,35 @@ pub(crate) mod price {
     }
 }
 
+
+/// a plain number, that is formatted as string (`$AmountString`)
+pub(crate) mod amount_string {
+    use pecunia::primitive_value::PrimitiveValue;
+    use pecunia::primitives::F64;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct AmountString {
+        #[serde(serialize_with = "PrimitiveValue::serialize_f64_str")]
+        #[serde(deserialize_with = "PrimitiveValue::deserialize_str")]
+        value: F64,
+    }
+
+    pub(crate) mod option {
+        use pecunia::primitives::F64;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use super::AmountString;
+
+        pub(crate) fn serialize<S>(value: &Option<F64>, serializer: S) -> Result<S::Ok, S::Error>
+            where S: Serializer {
+            value.map(|value| AmountString { value }).serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<F64>, D::Error>
+            where D: Deserializer<'de> {
+            Ok(Option::<AmountString>::deserialize(deserializer)?.map(|amount| amount.value))
+        }
+    }
+}
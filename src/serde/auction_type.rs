@@ -1,5 +1,7 @@
 use pecunia::{serde_vec, serde_with};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use wall_street::order::AuctionType;
 
 #[derive(Serialize, Deserialize)]
@@ -18,10 +20,47 @@ enum AuctionTypeDef {
 }
 
 serde_with!(Serializer for AuctionType as pub(crate) AuctionTypeSerializer with "AuctionTypeDef");
-serde_with!(Deserializer for AuctionType as pub(crate) AuctionTypeDeserializer with "AuctionTypeDef");
+
+fn parse(value: &str) -> Option<AuctionType> {
+    let value_deserializer: StrDeserializer<ValueError> = value.into_deserializer();
+    AuctionTypeDef::deserialize(value_deserializer).ok()
+}
+
+pub(crate) mod response_value {
+    use serde::Serializer;
+
+    use crate::types::response_value::ResponseValue;
+
+    use super::*;
+
+    pub(crate) fn serialize<S>(value: &ResponseValue<AuctionType>, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer {
+        match value {
+            ResponseValue::Known(known) => AuctionTypeDef::serialize(known, serializer),
+            ResponseValue::Unknown(unknown) => serializer.serialize_str(unknown),
+        }
+    }
+
+    /// auction types, that are unknown to this crate, are kept as [`ResponseValue::Unknown`]
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<ResponseValue<AuctionType>, D::Error>
+        where D: Deserializer<'de> {
+        crate::serde::unknown::response_value(deserializer, "AuctionType", parse)
+    }
+
+    /// whether the value is known and [`AuctionType::is_all`]
+    pub(crate) fn is_all(value: &ResponseValue<AuctionType>) -> bool {
+        value.known().map_or(false, AuctionType::is_all)
+    }
+}
 
 pub(crate) mod vec3 {
     use super::*;
 
-    serde_vec!(deserialize AuctionType as pub(crate) AuctionTypeVecDeserializer with AuctionTypeDeserializer, max=3);
+    serde_vec!(serialize AuctionType as pub(crate) AuctionTypeVecSerializer with AuctionTypeSerializer);
+
+    /// auction types, that are unknown to this crate, are skipped
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<AuctionType>, D::Error>
+        where D: Deserializer<'de> {
+        crate::serde::unknown::known_values(deserializer, "AuctionType", parse)
+    }
 }
@@ -1,11 +1,12 @@
 use chrono::Utc;
-use pecunia::price::{Price, TimeBoundedPrice};
-use pecunia::primitives::F64;
+use pecunia::prelude::*;
+use pecunia::price::TimeBoundedPrice;
 use serde::{Deserialize, Deserializer};
 use serde::de::DeserializeSeed;
 
-use crate::types::position::Position;
+use crate::types::position::{Hedgeability, Position};
 
+/// updates all fields of the position, that change with the market or by trading
 impl<'de> DeserializeSeed<'de> for &mut Position<'_> {
     type Value = ();
 
@@ -14,8 +15,19 @@ impl<'de> DeserializeSeed<'de> for &mut Position<'_> {
         let update_position = UpdatePosition::deserialize(deserializer)?;
 
         self.set_quantity(update_position.quantity);
+        self.set_available_quantity(update_position.available_quantity);
         self.set_current_price(update_position.current_price);
+        self.set_current_price_determinable(update_position.current_price_determinable);
+        self.set_previous_day_price(update_position.previous_day_price);
+        self.set_purchase_price(update_position.purchase_price);
         self.set_current_value(update_position.current_value);
+        self.set_purchase_value(update_position.purchase_value);
+        self.set_profit_loss_purchase(update_position.profit_loss_purchase);
+        self.set_profit_loss_purchase_relative(update_position.profit_loss_purchase_relative);
+        self.set_profit_loss_previous_day(update_position.profit_loss_previous_day);
+        self.set_profit_loss_previous_day_relative(update_position.profit_loss_previous_day_relative);
+        self.set_hedgeability(update_position.hedgeability);
+        self.set_available_quantity_to_hedge(update_position.available_quantity_to_hedge);
 
         Ok(())
     }
@@ -26,8 +38,41 @@ impl<'de> DeserializeSeed<'de> for &mut Position<'_> {
 struct UpdatePosition {
     #[serde(with = "crate::serde::amount_value::quantity")]
     quantity: F64,
+    #[serde(with = "crate::serde::amount_value::quantity")]
+    available_quantity: F64,
     #[serde(with = "crate::serde::time_bounded_price")]
     current_price: TimeBoundedPrice<Utc>,
+    #[serde(default)]
+    current_price_determinable: bool,
+    #[serde(default)]
+    #[serde(rename = "prevDayPrice")]
+    #[serde(with = "crate::serde::time_bounded_price::option")]
+    previous_day_price: Option<TimeBoundedPrice<Utc>>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    purchase_price: Option<Price>,
     #[serde(with = "crate::serde::amount_value::price")]
     current_value: Price,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    purchase_value: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPurchaseAbs")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    profit_loss_purchase: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPurchaseRel")]
+    profit_loss_purchase_relative: Option<Percent>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPrevDayAbs")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    profit_loss_previous_day: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPrevDayRel")]
+    profit_loss_previous_day_relative: Option<Percent>,
+    #[serde(default)]
+    hedgeability: Option<Hedgeability>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::quantity::option")]
+    available_quantity_to_hedge: Option<F64>,
 }
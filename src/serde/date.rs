@@ -43,6 +43,22 @@ pub(crate) mod date_string {
                 None => Option::<()>::None.serialize(serializer)
             }
         }
+
+        #[derive(Deserialize)]
+        #[serde(transparent)]
+        struct NaiveDateDeserializer {
+            #[serde(deserialize_with = "super::deserialize")]
+            remote: NaiveDate
+        }
+
+        #[allow(unused)]
+        pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+            where D: Deserializer<'de> {
+            Ok(
+                Option::<NaiveDateDeserializer>::deserialize(deserializer)?
+                    .map(|d| d.remote)
+            )
+        }
     }
 }
 
@@ -83,6 +99,16 @@ macro_rules! date_time {
                     remote: DateTime<$tz>
                 }
             
+                #[allow(unused)]
+                pub(crate) fn serialize<S>(date: &Option<DateTime<$tz>>, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer {
+                    match date {
+                        Some(date) => super::serialize(date, serializer),
+                        None => serializer.serialize_none()
+                    }
+                }
+
                 #[allow(unused)]
                 pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<$tz>>, D::Error>
                     where D: Deserializer<'de> {
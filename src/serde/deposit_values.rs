@@ -0,0 +1,162 @@
+//! Seeded deserialization of list responses, that belong to a deposit.
+//!
+//! Instead of deserializing a `JsonResponseValues<RawOrder>` and converting the resulting
+//! `Vec<RawOrder>` into a `Vec<Order<'d>>` afterwards, every value is converted right after
+//! it was deserialized. This way the values are only allocated once.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserializer;
+
+use crate::types::deposit::ComdirectDeposit;
+use crate::types::order::{Order, RawOrder};
+use crate::types::paging::Paging;
+use crate::types::position::{Position, RawPosition};
+use crate::types::transaction::{RawTransaction, Transaction};
+
+pub(crate) trait FromDepositRaw<'d>: Sized {
+    type Raw: DeserializeOwned;
+
+    fn from_deposit_raw(raw: Self::Raw, deposit: &'d ComdirectDeposit) -> Self;
+}
+
+/// deserializes a `{"paging": {..}, "aggregated": {..}, "values": [..]}` response
+pub(crate) struct DepositValues<'d, T, A = IgnoredAny> {
+    deposit: &'d ComdirectDeposit,
+    _values: PhantomData<(T, A)>,
+}
+
+pub(crate) struct DepositResponse<T, A> {
+    pub(crate) paging: Paging,
+    pub(crate) aggregated: Option<A>,
+    pub(crate) values: Vec<T>,
+}
+
+struct DepositValuesVisitor<'d, T, A> {
+    deposit: &'d ComdirectDeposit,
+    _values: PhantomData<(T, A)>,
+}
+
+struct DepositValuesVec<'v, 'd, T> {
+    deposit: &'d ComdirectDeposit,
+    vec: &'v mut Vec<T>,
+}
+
+impl<'d, T, A> DepositValues<'d, T, A> {
+    pub(crate) fn new(deposit: &'d ComdirectDeposit) -> Self {
+        Self { deposit, _values: PhantomData }
+    }
+}
+
+impl<'de, 'd, T: FromDepositRaw<'d>, A: DeserializeOwned> DeserializeSeed<'de> for DepositValues<'d, T, A> {
+    type Value = DepositResponse<T, A>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, <D as Deserializer<'de>>::Error> where
+        D: Deserializer<'de> {
+        deserializer.deserialize_map(DepositValuesVisitor { deposit: self.deposit, _values: PhantomData })
+    }
+}
+
+impl<'de, 'd, T: FromDepositRaw<'d>, A: DeserializeOwned> Visitor<'de> for DepositValuesVisitor<'d, T, A> {
+    type Value = DepositResponse<T, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a json response with paging and values")
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+        let mut paging = Paging::default();
+        let mut aggregated = None;
+        let mut values = Vec::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "paging" => paging = map.next_value()?,
+                "aggregated" => aggregated = map.next_value()?,
+                "values" => map.next_value_seed(DepositValuesVec { deposit: self.deposit, vec: &mut values })?,
+                _ => { map.next_value::<IgnoredAny>()?; }
+            }
+        }
+
+        Ok(DepositResponse { paging, aggregated, values })
+    }
+}
+
+impl<'de, 'd, T: FromDepositRaw<'d>> DeserializeSeed<'de> for DepositValuesVec<'_, 'd, T> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, <D as Deserializer<'de>>::Error> where
+        D: Deserializer<'de> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'd, T: FromDepositRaw<'d>> Visitor<'de> for DepositValuesVec<'_, 'd, T> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of values")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        if let Some(size) = seq.size_hint() {
+            self.vec.reserve(size);
+        }
+
+        while let Some(raw) = seq.next_element::<T::Raw>()? {
+            self.vec.push(T::from_deposit_raw(raw, self.deposit));
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! from_deposit_raw {
+    ($($ty:ident($raw:ty)),*) => {
+        $(
+            impl<'d> FromDepositRaw<'d> for $ty<'d> {
+                type Raw = $raw;
+
+                #[inline(always)]
+                fn from_deposit_raw(raw: Self::Raw, deposit: &'d ComdirectDeposit) -> Self {
+                    $ty::from_raw(raw, deposit)
+                }
+            }
+        )*
+    };
+}
+
+from_deposit_raw!(Order(RawOrder), Position(RawPosition), Transaction(RawTransaction));
+
+#[cfg(feature = "test")]
+pub mod bench {
+    //! Entry points for the `list_deserialization` benchmark, that compare the seeded
+    //! deserialization with the former deserialization into an intermediate vector.
+
+    use serde::de::DeserializeSeed;
+
+    use crate::error::Error;
+    use crate::serde::JsonResponseValues;
+    use crate::types::deposit::ComdirectDeposit;
+    use crate::types::order::{Order, RawOrder};
+
+    use super::DepositValues;
+
+    pub fn orders_seeded<'d>(json: &[u8], deposit: &'d ComdirectDeposit) -> Result<Vec<Order<'d>>, Error> {
+        let mut deserializer = serde_json::Deserializer::from_slice(json);
+        let orders = DepositValues::<Order>::new(deposit).deserialize(&mut deserializer)?;
+        // `serde_json::from_slice` rejects trailing characters as well
+        deserializer.end()?;
+        Ok(orders.values)
+    }
+
+    pub fn orders_intermediate<'d>(json: &[u8], deposit: &'d ComdirectDeposit) -> Result<Vec<Order<'d>>, Error> {
+        Ok(serde_json::from_slice::<JsonResponseValues<RawOrder>>(json)?
+            .values
+            .into_iter()
+            .map(|raw| Order::from_raw(raw, deposit))
+            .collect())
+    }
+}
@@ -1,5 +1,7 @@
 use pecunia::{serde_option, serde_vec, serde_with};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use wall_street::order::OrderDirection;
 
 #[derive(Serialize, Deserialize)]
@@ -13,6 +15,11 @@ enum OrderDirectionDef {
 serde_with!(Serializer for OrderDirection as pub(crate) OrderDirectionSerializer with "OrderDirectionDef");
 serde_with!(Deserializer for OrderDirection as pub(crate) OrderDirectionDeserializer with "OrderDirectionDef");
 
+fn parse(value: &str) -> Option<OrderDirection> {
+    let value_deserializer: StrDeserializer<ValueError> = value.into_deserializer();
+    OrderDirectionDef::deserialize(value_deserializer).ok()
+}
+
 pub(crate) mod option {
     use super::*;
 
@@ -24,5 +31,10 @@ pub(crate) mod vec2 {
     use super::*;
 
     serde_vec!(serialize OrderDirection as pub(crate) OrderDirectionVecSerializer with OrderDirectionSerializer);
-    serde_vec!(deserialize OrderDirection as pub(crate) OrderDirectionVecDeserializer with OrderDirectionDeserializer, max=2);
+
+    /// order directions, that are unknown to this crate, are skipped
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<OrderDirection>, D::Error>
+        where D: Deserializer<'de> {
+        crate::serde::unknown::known_values(deserializer, "OrderDirection", parse)
+    }
 }
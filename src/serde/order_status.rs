@@ -1,5 +1,7 @@
 use pecunia::serde_with;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use wall_street::order::OrderStatus;
 
 #[derive(Serialize, Deserialize)]
@@ -22,11 +24,23 @@ enum OrderStatusDef {
 }
 
 serde_with!(Serializer for OrderStatus as pub(crate) OrderStatusSerializer with "OrderStatusDef");
-serde_with!(Deserializer for OrderStatus as pub(crate) OrderStatusDeserializer with "OrderStatusDef");
+
+/// order states, that are unknown to this crate, are deserialized as [`OrderStatus::Unknown`]
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<OrderStatus, D::Error>
+    where D: Deserializer<'de> {
+    let value = String::deserialize(deserializer)?;
+    let value_deserializer: StrDeserializer<ValueError> = value.as_str().into_deserializer();
+    Ok(
+        OrderStatusDef::deserialize(value_deserializer)
+            .unwrap_or_else(|_| {
+                crate::serde::unknown::unknown_value("OrderStatus", &value);
+                OrderStatus::Unknown
+            })
+    )
+}
 
 pub(crate) mod option {
     use super::*;
 
     pecunia::serde_option!(serialize OrderStatus as pub(crate) OrderStatusOptionSerialier with OrderStatusSerializer);
-    pecunia::serde_option!(deserialize OrderStatus as pub(crate) OrderStatusOptionDeserialier with OrderStatusDeserializer);
 }
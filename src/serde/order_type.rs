@@ -1,5 +1,7 @@
 use pecunia::{serde_option, serde_with};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use wall_street::order::OrderType;
 
 #[derive(Serialize, Deserialize)]
@@ -20,7 +22,33 @@ enum OrderTypeDef {
 }
 
 serde_with!(Serializer for OrderType as pub(crate) OrderTypeSerializer with "OrderTypeDef");
-serde_with!(Deserializer for OrderType as pub(crate) OrderTypeDeserializer with "OrderTypeDef");
+
+fn parse(value: &str) -> Option<OrderType> {
+    let value_deserializer: StrDeserializer<ValueError> = value.into_deserializer();
+    OrderTypeDef::deserialize(value_deserializer).ok()
+}
+
+pub(crate) mod response_value {
+    use serde::Serializer;
+
+    use crate::types::response_value::ResponseValue;
+
+    use super::*;
+
+    pub(crate) fn serialize<S>(value: &ResponseValue<OrderType>, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer {
+        match value {
+            ResponseValue::Known(known) => OrderTypeDef::serialize(known, serializer),
+            ResponseValue::Unknown(unknown) => serializer.serialize_str(unknown),
+        }
+    }
+
+    /// order types, that are unknown to this crate, are kept as [`ResponseValue::Unknown`]
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<ResponseValue<OrderType>, D::Error>
+        where D: Deserializer<'de> {
+        crate::serde::unknown::response_value(deserializer, "OrderType", parse)
+    }
+}
 
 pub(crate) mod option {
     use super::*;
@@ -32,8 +60,9 @@ pub(crate) mod venue_map {
     use std::collections::HashMap;
 
     use serde::de::{MapAccess, Visitor};
-    use serde::Deserializer;
+    use serde::Serializer;
     use serde::export::fmt;
+    use serde::ser::SerializeMap;
 
     use crate::types::market_place::OrderTypeAbilities;
 
@@ -43,11 +72,21 @@ pub(crate) mod venue_map {
         deserializer.deserialize_map(MapVisitor)
     }
 
-    #[derive(Deserialize)]
-    struct OrderTypeDeserializer(
-        #[serde(with = "OrderTypeDef")]
-        OrderType
-    );
+    pub(crate) fn serialize<S>(map: &HashMap<OrderType, OrderTypeAbilities>, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        let mut serialize_map = serializer.serialize_map(Some(map.len()))?;
+        for (order_type, abilities) in map {
+            serialize_map.serialize_entry(&OrderTypeKey(order_type), abilities)?;
+        }
+        serialize_map.end()
+    }
+
+    struct OrderTypeKey<'o>(&'o OrderType);
+
+    impl Serialize for OrderTypeKey<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+            OrderTypeDef::serialize(self.0, serializer)
+        }
+    }
 
     struct MapVisitor;
 
@@ -64,11 +103,12 @@ pub(crate) mod venue_map {
                 None => HashMap::new()
             };
 
-            while let Some((order_type_deserializer, abilities)) = visitor.next_entry::<OrderTypeDeserializer, OrderTypeAbilities>()? {
-                values.insert(
-                    order_type_deserializer.0,
-                    abilities,
-                );
+            // order types, that are unknown to this crate, are skipped
+            while let Some((order_type, abilities)) = visitor.next_entry::<String, OrderTypeAbilities>()? {
+                match parse(&order_type) {
+                    Some(order_type) => { values.insert(order_type, abilities); }
+                    None => crate::serde::unknown::unknown_value("OrderType", &order_type),
+                }
             }
 
             Ok(values)
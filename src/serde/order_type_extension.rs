@@ -1,5 +1,7 @@
 use pecunia::{serde_vec, serde_with};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::IntoDeserializer;
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use wall_street::order::OrderTypeExtension;
 
 #[derive(Serialize, Deserialize)]
@@ -16,11 +18,47 @@ enum OrderTypeExtensionDef {
 }
 
 serde_with!(Serializer for OrderTypeExtension as pub(crate) OrderTypeExtensionSerializer with "OrderTypeExtensionDef");
-serde_with!(Deserializer for OrderTypeExtension as pub(crate) OrderTypeExtensionDeserializer with "OrderTypeExtensionDef");
+
+fn parse(value: &str) -> Option<OrderTypeExtension> {
+    let value_deserializer: StrDeserializer<ValueError> = value.into_deserializer();
+    OrderTypeExtensionDef::deserialize(value_deserializer).ok()
+}
+
+pub(crate) mod response_value {
+    use serde::Serializer;
+
+    use crate::types::response_value::ResponseValue;
+
+    use super::*;
+
+    pub(crate) fn serialize<S>(value: &ResponseValue<OrderTypeExtension>, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer {
+        match value {
+            ResponseValue::Known(known) => OrderTypeExtensionDef::serialize(known, serializer),
+            ResponseValue::Unknown(unknown) => serializer.serialize_str(unknown),
+        }
+    }
+
+    /// order type extensions, that are unknown to this crate, are kept as [`ResponseValue::Unknown`]
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<ResponseValue<OrderTypeExtension>, D::Error>
+        where D: Deserializer<'de> {
+        crate::serde::unknown::response_value(deserializer, "OrderTypeExtension", parse)
+    }
+
+    /// whether the value is known and [`OrderTypeExtension::is_none`]
+    pub(crate) fn is_none(value: &ResponseValue<OrderTypeExtension>) -> bool {
+        value.known().map_or(false, OrderTypeExtension::is_none)
+    }
+}
 
 pub(crate) mod vec3 {
     use super::*;
 
     serde_vec!(serialize OrderTypeExtension as pub(crate) OrderTypeExtensionOptionSerializer with OrderTypeExtensionSerializer);
-    serde_vec!(deserialize OrderTypeExtension as pub(crate) OrderTypeExtensionOptionDeserializer with OrderTypeExtensionDeserializer, max=3);
+
+    /// order type extensions, that are unknown to this crate, are skipped
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<OrderTypeExtension>, D::Error>
+        where D: Deserializer<'de> {
+        crate::serde::unknown::known_values(deserializer, "OrderTypeExtension", parse)
+    }
 }
@@ -25,9 +25,72 @@ pub enum OrderValidityDef {
 serde_with!(Serializer for OrderValidity as pub(crate) OrderValiditySerializer with "OrderValidityDef");
 serde_with!(Deserializer for OrderValidity as pub(crate) OrderValidityDeserializer with "OrderValidityDef");
 
+/// Order validities of outlines and order changes.
+///
+/// Relative validities are already resolved to `GTD` dates, when they are set (see
+/// [`resolve_validity`](crate::types::trading_calendar::resolve_validity)).
 pub(crate) mod option {
+    use serde::Serializer;
+
     use super::*;
 
-    serde_option!(serialize OrderValidity as pub(crate) OrderValidityOptionSerializer with OrderValiditySerializer);
+    pub(crate) fn serialize<S>(validity: &Option<OrderValidity>, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer {
+        match validity {
+            Some(validity) => OrderValidityDef::serialize(validity, serializer),
+            None => serializer.serialize_none()
+        }
+    }
+
     serde_option!(deserialize OrderValidity as pub(crate) OrderValidityOptionDeserializer with OrderValidityDeserializer);
 }
+
+/// Reads the `validityType` and `validity` fields of a flattened order validity.
+///
+/// `#[serde(default)]` can't be used together with `#[serde(flatten)]`
+/// ([serde issue 1626](https://github.com/serde-rs/serde/issues/1626)), so the default is
+/// applied here: orders without a `validityType` are valid for the current day.
+/// Unknown validity types are logged and kept as [`ResponseValue::Unknown`], without their date.
+pub(crate) mod flattened {
+    use serde::{Deserializer, Serializer};
+    use serde::de::Error;
+    use serde::ser::SerializeMap;
+
+    use crate::types::order::ComdirectOrderValidityType;
+    use crate::types::response_value::ResponseValue;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FlattenedValidity {
+        #[serde(default)]
+        validity_type: Option<ComdirectOrderValidityType>,
+        #[serde(default)]
+        #[serde(with = "crate::serde::date::date_string::option")]
+        validity: Option<NaiveDate>,
+    }
+
+    pub(crate) fn serialize<S>(validity: &ResponseValue<OrderValidity>, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer {
+        match validity {
+            ResponseValue::Known(validity) => super::serialize(validity, serializer),
+            ResponseValue::Unknown(validity_type) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("validityType", validity_type)?;
+                map.end()
+            }
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<ResponseValue<OrderValidity>, D::Error>
+        where D: Deserializer<'de> {
+        let flattened = FlattenedValidity::deserialize(deserializer)?;
+        match (flattened.validity_type, flattened.validity) {
+            (None, _) | (Some(ComdirectOrderValidityType::GoodForDay), _) => Ok(OrderValidity::OneDay.into()),
+            (Some(ComdirectOrderValidityType::GoodTillDate), Some(date)) => Ok(OrderValidity::TillDate(date).into()),
+            (Some(ComdirectOrderValidityType::GoodTillDate), None) => Err(D::Error::missing_field("validity")),
+            (Some(ComdirectOrderValidityType::Unknown(validity_type)), _) => Ok(ResponseValue::Unknown(validity_type)),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use pecunia::price::{Price, TimeBoundedPrice};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Serialize, Deserialize)]
 #[serde(remote = "TimeBoundedPrice::<Utc>")]
@@ -28,9 +28,36 @@ pub(crate) struct TimeBoundedPriceDeserializer {
     pub(crate) remote: TimeBoundedPrice<Utc>
 }
 
+pub(crate) fn serialize<S>(price: &TimeBoundedPrice<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer {
+    TimeBoundedPriceDef::serialize(price, serializer)
+}
+
 pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<TimeBoundedPrice<Utc>, D::Error>
     where
         D: Deserializer<'de> {
     Deserialize::deserialize(deserializer).map(|ok: TimeBoundedPriceDeserializer| ok.remote)
 }
 
+pub(crate) mod option {
+    use super::*;
+
+    pub(crate) fn serialize<S>(price: &Option<TimeBoundedPrice<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer {
+        match price {
+            Some(price) => super::serialize(price, serializer),
+            None => serializer.serialize_none()
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<TimeBoundedPrice<Utc>>, D::Error>
+        where
+            D: Deserializer<'de> {
+        Ok(
+            Option::<TimeBoundedPriceDeserializer>::deserialize(deserializer)?
+                .map(|ok| ok.remote)
+        )
+    }
+}
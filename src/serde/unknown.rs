@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Deserializer};
+
+use crate::types::response_value::ResponseValue;
+
+static LOG_UNKNOWN_VALUES: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables a warning for every enum value of a response, that is unknown to this
+/// crate (i.e. a new instrument type). Such values are deserialized as `Unknown(String)`,
+/// or as [`ResponseValue::Unknown`], if the enum has no `Unknown` variant. In lists of values,
+/// i.e. the abilities of a market place, they are skipped. Disabled by default.
+pub fn log_unknown_values(enabled: bool) {
+    LOG_UNKNOWN_VALUES.store(enabled, Ordering::Relaxed);
+}
+
+pub(crate) fn unknown_value(enum_name: &str, value: &str) {
+    if LOG_UNKNOWN_VALUES.load(Ordering::Relaxed) {
+        log::warn!("unknown {} value: {}", enum_name, value);
+    }
+}
+
+/// Deserializes a list of enum values and skips (and logs) the values, that are unknown to
+/// this crate. Used for enums, that don't have an `Unknown` variant.
+pub(crate) fn known_values<'de, D, T>(deserializer: D, enum_name: &str, parse: fn(&str) -> Option<T>) -> Result<Vec<T>, D::Error>
+    where D: Deserializer<'de> {
+    let values = Vec::<String>::deserialize(deserializer)?;
+    Ok(
+        values.iter()
+            .filter_map(|value| parse(value).or_else(|| {
+                unknown_value(enum_name, value);
+                None
+            }))
+            .collect()
+    )
+}
+
+/// Deserializes an enum value, that is kept as [`ResponseValue::Unknown`] (and logged), if it is
+/// unknown to this crate.
+pub(crate) fn response_value<'de, D, T>(deserializer: D, enum_name: &str, parse: fn(&str) -> Option<T>) -> Result<ResponseValue<T>, D::Error>
+    where D: Deserializer<'de> {
+    let value = String::deserialize(deserializer)?;
+    Ok(match parse(&value) {
+        Some(known) => ResponseValue::Known(known),
+        None => {
+            unknown_value(enum_name, &value);
+            ResponseValue::Unknown(value)
+        }
+    })
+}
@@ -4,39 +4,33 @@ new_type_ids!(
     pub(crate) struct TanChallengeId
 );
 
+/// The TAN challenge of comdirect, that has to be answered to confirm an action.
 #[derive(Debug, Deserialize, getset::Getters)]
 #[getset(get = "pub(crate)")]
-pub(crate) struct TanChallenge {
+pub struct TanChallenge {
     id: TanChallengeId,
+    #[getset(get = "pub")]
     typ: TanChallengeType,
+    #[getset(get = "pub")]
     #[serde(rename = "availableTypes")]
     available_types: Vec<TanChallengeType>,
+    /// i.e. the base64 encoded image of a photo TAN
+    #[getset(get = "pub")]
     challenge: Option<String>,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
-pub(crate) enum TanChallengeType {
-    #[serde(rename = "P_TAN_PUSH")]
-    PushTan,
-    #[serde(rename = "P_TAN")]
-    PhotoTan,
-    #[serde(rename = "P_TAN_APP")]
-    PhotoTanApp,
-    #[serde(rename = "M_TAN")]
-    MobileTan,
-    #[serde(rename = "TAN_FREI")]
-    Free,
+response_enum! {
+    pub enum TanChallengeType {
+        PushTan = "P_TAN_PUSH",
+        PhotoTan = "P_TAN",
+        PhotoTanApp = "P_TAN_APP",
+        MobileTan = "M_TAN",
+        Free = "TAN_FREI",
+    }
 }
 
 impl TanChallengeType {
-    pub const fn to_authentication_info(&self) -> &'static str {
-        use TanChallengeType::*;
-        match self {
-            PushTan => r#"{"typ":"P_TAN_PUSH"}"#,
-            PhotoTan => r#"{"typ":"P_TAN"}"#,
-            PhotoTanApp => r#"{"typ":"P_TAN_APP"}"#,
-            MobileTan => r#"{"typ":"M_TAN"}"#,
-            Free => r#"{"typ":"TAN_FREI"}"#,
-        }
+    pub fn to_authentication_info(&self) -> String {
+        format!(r#"{{"typ":"{}"}}"#, self.as_str())
     }
 }
@@ -1,6 +1,6 @@
 use pecunia::prelude::*;
 use pecunia::units::currency::Currency;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::order::Order;
 use crate::types::order::order_change::OrderChange;
@@ -26,7 +26,7 @@ pub enum ChangeCostIndication<'oc, 'o, 'd> {
 }
 
 
-#[derive(Clone, Debug, Deserialize, PartialEq, getset::Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
 #[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawCostIndication {
@@ -55,7 +55,7 @@ pub struct RawCostIndication {
     total_holding_costs: TotalHoldingCosts,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, getset::Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
 pub struct ExchangeRate {
     #[serde(with = "crate::serde::amount_value::price")]
     bid: Price,
@@ -63,7 +63,7 @@ pub struct ExchangeRate {
     ask: Price,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, getset::Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
 #[serde(rename_all = "camelCase")]
 pub struct CostGroup {
     #[serde(rename = "type")]
@@ -77,17 +77,15 @@ pub struct CostGroup {
     costs: Vec<CostEntry>,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
-pub enum CostGroupType {
-    #[serde(rename = "K")]
-    PurchaseCosts,
-    #[serde(rename = "H")]
-    HoldingCosts,
-    #[serde(rename = "V")]
-    SellingCosts,
+response_enum! {
+    pub enum CostGroupType {
+        PurchaseCosts = "K",
+        HoldingCosts = "H",
+        SellingCosts = "V",
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CostEntry {
     #[serde(rename = "type")]
@@ -100,17 +98,15 @@ pub struct CostEntry {
     inducement: Option<Inducement>,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
-pub enum CostEntryType {
-    #[serde(rename = "E")]
-    InternCosts,
-    #[serde(rename = "F")]
-    ExternCosts,
-    #[serde(rename = "P")]
-    ProductionCosts,
+response_enum! {
+    pub enum CostEntryType {
+        InternCosts = "E",
+        ExternCosts = "F",
+        ProductionCosts = "P",
+    }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Inducement {
     #[serde(with = "crate::serde::amount_value::price")]
     amount: Price,
@@ -118,7 +114,7 @@ pub struct Inducement {
     estimated: bool,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TotalCostDetail {
     service_costs: TotalCostEntry,
@@ -128,7 +124,7 @@ pub struct TotalCostDetail {
     product_costs: TotalCostEntry,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TotalCostEntry {
     #[serde(rename = "type")]
@@ -139,14 +135,14 @@ pub struct TotalCostEntry {
     average_return_pa: Option<Percent>,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct TotalHoldingCosts {
     year1: TotalHoldingCostEntry,
     year2: TotalHoldingCostEntry,
     sales: TotalHoldingCostEntry,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TotalHoldingCostEntry {
     #[serde(rename = "type")]
@@ -157,14 +153,12 @@ pub struct TotalHoldingCostEntry {
     average_return_pa: Option<Percent>,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
-pub enum TotalHoldingCostEntryType {
-    #[serde(rename = "IM_ERSTEN_JAHR")]
-    FirstYear,
-    #[serde(rename = "IM_ZWEITEN_JAHR")]
-    SecondYear,
-    #[serde(rename = "IM_JAHR_DER_VERAUESSERUNG")]
-    YearOfSell,
+response_enum! {
+    pub enum TotalHoldingCostEntryType {
+        FirstYear = "IM_ERSTEN_JAHR",
+        SecondYear = "IM_ZWEITEN_JAHR",
+        YearOfSell = "IM_JAHR_DER_VERAUESSERUNG",
+    }
 }
 
 impl<'o, 'd, 'i, 'm> CostIndication<'o, 'd, 'i, 'm> {
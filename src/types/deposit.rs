@@ -1,18 +1,17 @@
 use derive_more::Display;
+use pecunia::prelude::*;
 use serde::{Deserialize, Serialize};
 use wall_street::deposit::Deposit;
 
+use crate::types::order::{Order, OrderId};
+use crate::types::position::{Position, PositionChanges, PositionId};
+
 new_type_ids!(
     pub struct DepositId
     // [deserialize]
     pub struct DepositDisplayId
 );
 
-// todo: create a different kind of deposit, that enforces, that every Position and Order is valid
-// this can be done, by creating a DepositPositions and DepositOrders struct (it's important
-// to have one for both, so you can keep references to positions, if you update orders)
-// then store these structs in a Deposit. More thinking needs to be done...
-
 #[derive(Clone, Debug, Serialize, Deserialize, Display, PartialEq, getset::Getters)]
 #[getset(get = "pub")]
 #[display(fmt = "{}", display_id)]
@@ -24,6 +23,200 @@ pub struct ComdirectDeposit {
     display_id: DepositDisplayId,
 }
 
+/// The depot totals, that come with every positions request
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct DepotSummary {
+    #[serde(with = "crate::serde::amount_value::price")]
+    current_value: Price,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    purchase_value: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "prevDayValue")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    previous_day_value: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPurchaseAbs")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    profit_loss_purchase: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPurchaseRel")]
+    profit_loss_purchase_relative: Option<Percent>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPrevDayAbs")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    profit_loss_previous_day: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPrevDayRel")]
+    profit_loss_previous_day_relative: Option<Percent>,
+}
+
+#[derive(Clone, Debug, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+pub struct DepotOverview<'d> {
+    summary: DepotSummary,
+    positions: Vec<Position<'d>>,
+}
+
+impl<'d> DepotOverview<'d> {
+    pub(crate) fn new(summary: DepotSummary, positions: Vec<Position<'d>>) -> Self {
+        Self { summary, positions }
+    }
+
+    #[inline(always)]
+    pub fn into_parts(self) -> (DepotSummary, Vec<Position<'d>>) {
+        (self.summary, self.positions)
+    }
+}
+
+/// A deposit together with its positions and open orders.
+///
+/// Positions and orders are kept in two separate containers, so it is possible to hold
+/// references into the positions, while the orders are refreshed (see [`split_mut`](ManagedDeposit::split_mut)).
+/// The positions are always loaded with their instrument, so orders can be linked to them.
+#[derive(Debug, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+pub struct ManagedDeposit<'d> {
+    deposit: &'d ComdirectDeposit,
+    positions: DepositPositions<'d>,
+    orders: DepositOrders<'d>,
+}
+
+/// all positions of one deposit
+#[derive(Clone, Debug, PartialEq, getset::Getters)]
+pub struct DepositPositions<'d> {
+    #[getset(get = "pub")]
+    deposit: &'d ComdirectDeposit,
+    positions: Vec<Position<'d>>,
+}
+
+/// all open orders of one deposit
+#[derive(Debug, PartialEq, getset::Getters)]
+pub struct DepositOrders<'d> {
+    #[getset(get = "pub")]
+    deposit: &'d ComdirectDeposit,
+    orders: Vec<Order<'d>>,
+}
+
+impl<'d> ManagedDeposit<'d> {
+    pub(crate) fn new(positions: DepositPositions<'d>, orders: DepositOrders<'d>) -> Self {
+        Self {
+            deposit: positions.deposit,
+            positions,
+            orders,
+        }
+    }
+
+    #[inline(always)]
+    pub fn split_mut(&mut self) -> (&mut DepositPositions<'d>, &mut DepositOrders<'d>) {
+        (&mut self.positions, &mut self.orders)
+    }
+
+    #[inline(always)]
+    pub fn into_parts(self) -> (DepositPositions<'d>, DepositOrders<'d>) {
+        (self.positions, self.orders)
+    }
+
+    /// all open orders, that belong to the instrument of the position
+    pub fn orders_of<'s>(&'s self, position: &'s Position) -> impl Iterator<Item=&'s Order<'d>> + 's {
+        self.orders.of_position(position)
+    }
+
+    /// the position, that holds the instrument of the order (if any)
+    pub fn position_of(&self, order: &Order) -> Option<&Position<'d>> {
+        self.positions.of_order(order)
+    }
+}
+
+impl<'d> DepositPositions<'d> {
+    pub(crate) fn new(deposit: &'d ComdirectDeposit, positions: Vec<Position<'d>>) -> Self {
+        Self { deposit, positions }
+    }
+
+    #[inline(always)]
+    pub fn get(&self, position_id: &PositionId) -> Option<&Position<'d>> {
+        self.positions.iter().find(|p| p.raw().id() == position_id)
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> std::slice::Iter<Position<'d>> {
+        self.positions.iter()
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn of_order(&self, order: &Order) -> Option<&Position<'d>> {
+        self.positions
+            .iter()
+            .find(|p| p.holds_instrument(order.instrument_id()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [Position<'d>] {
+        &mut self.positions
+    }
+
+    /// adds the new positions and removes the closed ones
+    pub(crate) fn apply_changes(&mut self, changes: &PositionChanges<'d>) {
+        self.positions.retain(|p| !changes.closed().contains(p.raw().id()));
+        self.positions.extend(changes.added().iter().cloned());
+    }
+}
+
+impl<'d> DepositOrders<'d> {
+    pub(crate) fn new(deposit: &'d ComdirectDeposit, orders: Vec<Order<'d>>) -> Self {
+        Self { deposit, orders }
+    }
+
+    #[inline(always)]
+    pub fn get(&self, order_id: &OrderId) -> Option<&Order<'d>> {
+        self.orders.iter().find(|o| o.id() == order_id)
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> std::slice::Iter<Order<'d>> {
+        self.orders.iter()
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    pub fn of_position<'s>(&'s self, position: &'s Position) -> impl Iterator<Item=&'s Order<'d>> + 's {
+        self.orders
+            .iter()
+            .filter(move |o| position.holds_instrument(o.instrument_id()))
+    }
+
+    /// Replaces the orders with the current open orders. Orders, that are still open, keep
+    /// their place, new orders are appended and orders, that are not open anymore, are removed.
+    pub(crate) fn replace(&mut self, open_orders: Vec<Order<'d>>) {
+        self.orders.retain(|o| open_orders.iter().any(|open| open.id() == o.id()));
+        for order in open_orders {
+            match self.orders.iter_mut().find(|o| o.id() == order.id()) {
+                Some(existing) => *existing = order,
+                None => self.orders.push(order),
+            }
+        }
+    }
+}
+
 impl Deposit for ComdirectDeposit {
     fn id(&self) -> &str { &self.id.as_str() }
 }
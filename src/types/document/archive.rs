@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::types::document::{Document, DocumentCategory, DocumentId};
+
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+const MANIFEST_VERSION: u32 = 1;
+const MAX_FILE_NAME_LENGTH: usize = 100;
+
+/// Keeps track of all documents, that were already stored in an archive directory.
+/// The manifest is stored as `manifest.json` next to the archived documents.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PostBoxManifest {
+    version: u32,
+    documents: HashMap<DocumentId, ManifestEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEntry {
+    file: String,
+    sidecar: String,
+    #[serde(with = "crate::serde::date::date_time_string_utc")]
+    archived_at: DateTime<Utc>,
+}
+
+/// The JSON sidecar, that is written next to every archived document
+#[derive(Serialize)]
+struct DocumentSidecar<'d> {
+    category: DocumentCategory,
+    #[serde(flatten)]
+    document: &'d Document,
+}
+
+impl PostBoxManifest {
+    pub fn load(dir: &Path) -> Result<Self, Error> {
+        let path = dir.join(MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let manifest = fs::read(path)?;
+        Ok(serde_json::from_slice(&manifest)?)
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), Error> {
+        let manifest = serde_json::to_vec_pretty(self)?;
+        fs::write(dir.join(MANIFEST_FILE_NAME), manifest)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, document_id: &DocumentId) -> bool {
+        self.documents.contains_key(document_id)
+    }
+
+    #[inline(always)]
+    pub fn get(&self, document_id: &DocumentId) -> Option<&ManifestEntry> {
+        self.documents.get(document_id)
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// writes the document content and its JSON sidecar into `dir` and records it in the manifest
+    pub(crate) fn archive(&mut self, dir: &Path, document: &Document, content: &[u8]) -> Result<(), Error> {
+        let file = Self::file_name(document);
+        let sidecar = format!("{}.json", file);
+
+        fs::write(dir.join(&file), content)?;
+        let sidecar_content = serde_json::to_vec_pretty(&DocumentSidecar {
+            category: document.category(),
+            document,
+        })?;
+        fs::write(dir.join(&sidecar), sidecar_content)?;
+
+        self.documents.insert(
+            document.id().clone(),
+            ManifestEntry { file, sidecar, archived_at: Utc::now() },
+        );
+
+        Ok(())
+    }
+
+    /// builds a file name in the format `<YYYY-mm-dd>_<category>_<name>_<id>.<extension>`
+    ///
+    /// The document id is part of the name, since comdirect uses the same name for
+    /// different documents of the same day (i.e. two trade confirmations).
+    fn file_name(document: &Document) -> String {
+        let name: String = document
+            .name()
+            .as_str()
+            .chars()
+            .map(|c| match c.is_alphanumeric() || c == '-' || c == '.' {
+                true => c,
+                false => '_'
+            })
+            .take(MAX_FILE_NAME_LENGTH)
+            .collect();
+
+        format!(
+            "{}_{}_{}_{}.{}",
+            document.creation().format("%F"),
+            document.category(),
+            name,
+            document.id().as_str(),
+            document.file_extension(),
+        )
+    }
+}
+
+impl Default for PostBoxManifest {
+    fn default() -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            documents: HashMap::new(),
+        }
+    }
+}
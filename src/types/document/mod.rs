@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+pub mod archive;
+
+new_type_ids!(
+    #[derive(Hash)]
+    pub struct DocumentId
+    pub struct DocumentName
+);
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    #[serde(rename = "documentId")]
+    id: DocumentId,
+    #[serde(with = "DocumentName")]
+    name: DocumentName,
+    #[serde(rename = "dateCreation")]
+    #[serde(with = "crate::serde::date::date_string")]
+    creation: NaiveDate,
+    mime_type: String,
+    #[serde(default)]
+    #[serde(alias = "deleteable")]
+    deletable: bool,
+    #[serde(default)]
+    advertisement: bool,
+    #[serde(rename = "documentMetaData")]
+    #[serde(alias = "documentMetadata")]
+    metadata: DocumentMetadata,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters, getset::CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentMetadata {
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    archived: bool,
+    #[serde(default)]
+    #[serde(with = "crate::serde::date::date_string::option")]
+    #[getset(get = "pub")]
+    date_read: Option<NaiveDate>,
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    already_read: bool,
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    predocument_exists: bool,
+}
+
+/// The comdirect API does not deliver a category for PostBox documents. Therefore the
+/// category is derived from the name of the document, which follows a fixed scheme
+/// for all documents generated by comdirect (i.e. `Wertpapierabrechnung Kauf ...`).
+#[derive(Copy, Clone, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DocumentCategory {
+    #[display(fmt = "trade_confirmation")]
+    TradeConfirmation,
+    #[display(fmt = "dividend")]
+    Dividend,
+    #[display(fmt = "interest")]
+    Interest,
+    #[display(fmt = "tax")]
+    Tax,
+    #[display(fmt = "statement")]
+    Statement,
+    #[display(fmt = "advertisement")]
+    Advertisement,
+    #[display(fmt = "other")]
+    Other,
+}
+
+impl Document {
+    pub fn category(&self) -> DocumentCategory {
+        use DocumentCategory::*;
+
+        if self.advertisement {
+            return Advertisement;
+        }
+
+        let name = self.name.as_str().to_lowercase();
+        let contains_any = |patterns: &[&str]| patterns
+            .iter()
+            .any(|pattern| name.contains(pattern));
+
+        if contains_any(&["steuer", "tax"]) {
+            Tax
+        } else if contains_any(&["zins"]) {
+            // before the dividends, because of names like `Zinsertrag`
+            Interest
+        } else if contains_any(&["dividende", "ertrag", "ausschüttung"]) {
+            Dividend
+        } else if contains_any(&["wertpapierabrechnung", "kauf", "verkauf", "order"]) {
+            TradeConfirmation
+        } else if contains_any(&["auszug", "finanzreport", "report", "saldo"]) {
+            Statement
+        } else {
+            Other
+        }
+    }
+
+    /// the file extension matching the [`mime_type`](Document::mime_type) of the document
+    pub fn file_extension(&self) -> &'static str {
+        match self.mime_type.as_str() {
+            "application/pdf" => "pdf",
+            "text/html" => "html",
+            "text/plain" => "txt",
+            _ => "bin"
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use wall_street::derivative::Derivative;
+
+use crate::error::Error;
+use crate::types::instrument::{Instrument, InstrumentId};
+
+const CACHE_VERSION: u32 = 1;
+
+/// the time to live of cached instruments, if not specified otherwise
+pub const DEFAULT_TTL_HOURS: i64 = 24;
+
+/// An in memory cache of instruments, that can optionally be persisted on disk.
+///
+/// Every instrument can be looked up by its instrument id, ISIN, WKN and symbol.
+/// Instruments, that are older than the time to live, are treated as if they were missing.
+#[derive(Clone, Debug)]
+pub struct InstrumentCache {
+    ttl: Duration,
+    instruments: HashMap<InstrumentId, CacheEntry>,
+    /// maps the ISIN, WKN and symbol to the instrument id
+    keys: HashMap<CacheKey, InstrumentId>,
+}
+
+/// The kind of a key is part of the key, because a symbol can look like the WKN of another
+/// instrument.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum CacheKey {
+    Isin(String),
+    Wkn(String),
+    Symbol(String),
+}
+
+impl CacheKey {
+    fn of(derivative: &Derivative) -> Option<Self> {
+        let value: String = derivative.clone().into();
+        #[allow(unreachable_patterns)]
+        match derivative {
+            Derivative::ISIN(_) => Some(CacheKey::Isin(value)),
+            Derivative::WKN(_) => Some(CacheKey::Wkn(value)),
+            Derivative::SYMBOL(_) => Some(CacheKey::Symbol(value)),
+            // instruments are only cached by their ISIN, WKN and symbol
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEntry {
+    instrument: Instrument,
+    #[serde(with = "crate::serde::date::date_time_string_utc")]
+    cached_at: DateTime<Utc>,
+}
+
+/// the on disk format of the cache
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    instruments: Vec<CacheEntry>,
+}
+
+impl InstrumentCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            instruments: HashMap::new(),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Loads a cache, that was stored with [`InstrumentCache::save`].
+    /// If the file does not exist, an empty cache is returned.
+    pub fn load(path: &Path, ttl: Duration) -> Result<Self, Error> {
+        let mut cache = Self::new(ttl);
+        if !path.exists() {
+            return Ok(cache);
+        }
+
+        let file: CacheFile = serde_json::from_slice(&fs::read(path)?)?;
+        if file.version != CACHE_VERSION {
+            return Err(Error::UnsupportedSchemaVersion);
+        }
+
+        for entry in file.instruments {
+            cache.insert_entry(entry);
+        }
+        cache.remove_expired();
+
+        Ok(cache)
+    }
+
+    /// stores all instruments, that are not expired yet
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let now = Utc::now();
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            instruments: self.instruments
+                .values()
+                .filter(|entry| !self.is_expired(entry, now))
+                .cloned()
+                .collect(),
+        };
+        fs::write(path, serde_json::to_vec(&file)?)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn set_ttl(&mut self, ttl: Duration) {
+        self.ttl = ttl;
+    }
+
+    pub fn get(&self, derivative: &Derivative) -> Option<&Instrument> {
+        self.keys
+            .get(&CacheKey::of(derivative)?)
+            .and_then(|id| self.get_by_id(id))
+    }
+
+    pub fn get_by_id(&self, id: &InstrumentId) -> Option<&Instrument> {
+        self.instruments
+            .get(id)
+            .filter(|entry| !self.is_expired(entry, Utc::now()))
+            .map(|entry| &entry.instrument)
+    }
+
+    pub fn insert(&mut self, instrument: Instrument) {
+        self.insert_entry(CacheEntry { instrument, cached_at: Utc::now() });
+    }
+
+    pub fn remove(&mut self, id: &InstrumentId) -> Option<Instrument> {
+        let entry = self.instruments.remove(id)?;
+        self.keys.retain(|_, key_id| key_id != id);
+        Some(entry.instrument)
+    }
+
+    pub fn remove_expired(&mut self) {
+        let now = Utc::now();
+        let ttl = self.ttl;
+        self.instruments.retain(|_, entry| entry.cached_at + ttl > now);
+
+        let instruments = &self.instruments;
+        self.keys.retain(|_, id| instruments.contains_key(id));
+    }
+
+    pub fn clear(&mut self) {
+        self.instruments.clear();
+        self.keys.clear();
+    }
+
+    /// the number of cached instruments, including the expired ones
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.instruments.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.instruments.is_empty()
+    }
+
+    fn insert_entry(&mut self, entry: CacheEntry) {
+        let instrument = &entry.instrument;
+        let id = instrument.id().clone();
+
+        // the identifiers of a re-inserted instrument might have changed
+        if self.instruments.contains_key(&id) {
+            self.keys.retain(|_, key_id| key_id != &id);
+        }
+
+        self.keys.insert(CacheKey::Isin(Derivative::ISIN(instrument.isin().clone()).into()), id.clone());
+        self.keys.insert(CacheKey::Wkn(Derivative::WKN(instrument.wkn().clone()).into()), id.clone());
+        self.keys.insert(CacheKey::Symbol(Derivative::SYMBOL(instrument.symbol().clone()).into()), id.clone());
+
+        self.instruments.insert(id, entry);
+    }
+
+    #[inline(always)]
+    fn is_expired(&self, entry: &CacheEntry, now: DateTime<Utc>) -> bool {
+        entry.cached_at + self.ttl <= now
+    }
+}
+
+impl Default for InstrumentCache {
+    fn default() -> Self {
+        Self::new(Duration::hours(DEFAULT_TTL_HOURS))
+    }
+}
@@ -0,0 +1,184 @@
+use chrono::{NaiveDate, Utc};
+use pecunia::prelude::*;
+use pecunia::price::TimeBoundedPrice;
+use pecunia::units::currency::Currency;
+use serde::{Deserialize, Serialize};
+use wall_street::derivative::{Derivative, ISIN, SYMBOL, WKN};
+
+pub mod cache;
+
+new_type_ids!(
+    #[derive(Hash)]
+    pub struct InstrumentId
+    pub struct InstrumentName
+);
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct Instrument {
+    #[serde(with = "InstrumentId")]
+    #[serde(rename = "instrumentId")]
+    id: InstrumentId,
+    #[serde(with = "InstrumentName")]
+    name: InstrumentName,
+    isin: ISIN,
+    #[serde(rename = "mnemonic")]
+    symbol: SYMBOL,
+    wkn: WKN,
+    static_data: StaticInstrumentData,
+    derivative_data: Option<DerivativeData>,
+    #[serde(rename = "fundDistribution")]
+    #[serde(alias = "fundsDistribution")]
+    #[serde(alias = "fundData")]
+    fund_data: Option<FundData>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct StaticInstrumentData {
+    instrument_type: InstrumentType,
+    currency: Currency,
+}
+
+response_enum! {
+    pub enum InstrumentType {
+        Share = "SHARE",
+        Bonds = "BONDS",
+        SubscriptionRight = "SUBSCRIPTION_RIGHT",
+        ETF = "ETF",
+        ProfitPartCertificate = "PROFIT_PART_CERTIFICATE",
+        Fund = "FUND",
+        Warrant = "WARRANT",
+        Certificate = "CERTIFICATE",
+        NotAvailable = "NOT_AVAILABLE",
+    }
+}
+
+/// Additional data of derivatives, i.e. warrants, certificates and bonds.
+///
+/// Which fields are present depends on the kind of the derivative.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct DerivativeData {
+    #[serde(default)]
+    underlying_instrument: Option<UnderlyingInstrument>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::time_bounded_price::option")]
+    underlying_price: Option<TimeBoundedPrice<Utc>>,
+    /// e.g. "Hebel", "Discount" or "Bonus"
+    #[serde(default)]
+    certificate_type: Option<String>,
+    #[serde(default)]
+    rating: Option<Rating>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    strike_price: Option<Price>,
+    #[serde(default)]
+    leverage: Option<String>,
+    /// the ratio of the derivative to its underlying
+    #[serde(default)]
+    #[serde(rename = "multiplier")]
+    ratio: Option<String>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::date::date_string::option")]
+    expiry_date: Option<NaiveDate>,
+    #[serde(default)]
+    #[serde(rename = "yieldPA")]
+    yield_pa: Option<Percent>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::amount_string::option")]
+    remaining_term_in_years: Option<F64>,
+    #[serde(default)]
+    nominal_rate: Option<Percent>,
+    #[serde(default)]
+    warrant_type: Option<WarrantType>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::date::date_string::option")]
+    maturity_date: Option<NaiveDate>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::date::date_string::option")]
+    interest_payment_date: Option<NaiveDate>,
+    #[serde(default)]
+    interest_payment_interval: Option<InterestPaymentInterval>,
+}
+
+/// The underlying of a derivative.
+///
+/// Unlike an [`Instrument`], an underlying (e.g. an index) does not necessarily have
+/// an ISIN, WKN or symbol.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct UnderlyingInstrument {
+    #[serde(default)]
+    #[serde(rename = "instrumentId")]
+    id: Option<InstrumentId>,
+    #[serde(default)]
+    name: Option<InstrumentName>,
+    #[serde(default)]
+    isin: Option<ISIN>,
+    #[serde(default)]
+    #[serde(rename = "mnemonic")]
+    symbol: Option<SYMBOL>,
+    #[serde(default)]
+    wkn: Option<WKN>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, getset::Getters)]
+#[getset(get = "pub")]
+pub struct Rating {
+    /// the rating of funds
+    #[serde(default)]
+    morningstar: Option<String>,
+    /// the rating of bonds
+    #[serde(default)]
+    moodys: Option<String>,
+}
+
+response_enum! {
+    pub enum WarrantType {
+        Call = "Call",
+        Put = "Put",
+    }
+}
+
+response_enum! {
+    pub enum InterestPaymentInterval {
+        Annually = "ANNUALLY",
+        SemiAnnually = "SEMIANNUALLY",
+        Quarterly = "QUARTERLY",
+        Monthly = "MONTHLY",
+        Other = "OTHER",
+    }
+}
+
+/// Additional data of funds and ETFs.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+#[serde(rename_all = "camelCase")]
+pub struct FundData {
+    currency: Currency,
+    #[serde(default)]
+    regular_issue_surcharge: Option<Percent>,
+    #[serde(default)]
+    discount_issue_surcharge: Option<Percent>,
+    #[serde(default)]
+    reduced_issue_surcharge: Option<Percent>,
+    /// e.g. "Aktienfonds", "Rentenfonds" or "Dachfonds"
+    #[serde(default)]
+    investment_category: Option<String>,
+    /// the ongoing charges
+    #[serde(default)]
+    total_expense_ratio: Option<Percent>,
+    #[serde(default)]
+    rating: Option<Rating>,
+}
+
+impl From<Derivative> for InstrumentId {
+    fn from(d: Derivative) -> Self {
+        Self(d.into())
+    }
+}
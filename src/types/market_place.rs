@@ -14,7 +14,7 @@ new_type_ids!(
     pub struct MarketPlaceName
 );
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters)]
 #[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct MarketPlace {
@@ -31,7 +31,7 @@ pub struct MarketPlace {
     order_types: HashMap<OrderType, OrderTypeAbilities>,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters)]
 #[getset(get = "pub")]
 pub struct OrderTypeAbilities {
     #[serde(rename = "limitExtensions")]
@@ -1,8 +1,15 @@
 pub mod deposit;
+pub mod document;
 pub mod instrument;
 pub mod market_place;
 pub mod order;
+pub mod owned;
+pub mod paging;
 pub mod cost_indication;
 pub mod position;
+pub mod quantity;
 pub mod quote;
+pub mod response_value;
+pub mod schema;
+pub mod trading_calendar;
 pub mod transaction;
@@ -0,0 +1,100 @@
+//! Combination orders, that consist of two sub orders (legs).
+//!
+//! comdirect knows two kinds of combination orders:
+//! - `ONE_CANCELS_OTHER`: both legs are active, once one leg is executed, the other one is canceled
+//! - `NEXT_ORDER`: the second leg is waiting, until the first leg was executed
+
+use wall_street::order::{OrderStatus, OrderType};
+
+/// one of the two sub orders of a combination order
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Leg {
+    First,
+    Second,
+}
+
+/// The status of a combination order, that takes both legs into account
+#[derive(Copy, Clone, Debug, PartialEq, getset::CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct CombinationOrderStatus {
+    /// `None`, if the order type is unknown to this crate
+    order_type: Option<OrderType>,
+    first: OrderStatus,
+    second: OrderStatus,
+}
+
+impl Leg {
+    /// the index of the leg in the sub orders
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        match self {
+            Leg::First => 0,
+            Leg::Second => 1,
+        }
+    }
+
+    #[inline(always)]
+    pub fn other(self) -> Self {
+        match self {
+            Leg::First => Leg::Second,
+            Leg::Second => Leg::First,
+        }
+    }
+}
+
+impl CombinationOrderStatus {
+    pub(crate) fn new(order_type: Option<OrderType>, first: OrderStatus, second: OrderStatus) -> Self {
+        Self { order_type, first, second }
+    }
+
+    #[inline(always)]
+    pub fn leg(&self, leg: Leg) -> OrderStatus {
+        match leg {
+            Leg::First => self.first,
+            Leg::Second => self.second,
+        }
+    }
+
+    /// The status of the combination order as a whole.
+    ///
+    /// - one cancels other: the status of the leg, that got the furthest,
+    ///   i.e. `Executed` once one leg was executed
+    /// - next order: the status of the first leg until it was executed, the status of the
+    ///   second leg afterwards (a second leg, that is still waiting, counts as `Open`)
+    pub fn overall(&self) -> OrderStatus {
+        use OrderStatus::*;
+
+        match self.order_type {
+            Some(OrderType::OneTriggersTheOther) => match (self.first, self.second) {
+                (Executed, Pending) => Open,
+                (Executed, second) => second,
+                (first, _) => first,
+            },
+            _ => match (self.first, self.second) {
+                (Executed, _) | (_, Executed) => Executed,
+                (PartiallyExecuted, _) | (_, PartiallyExecuted) => PartiallyExecuted,
+                (Open, _) | (_, Open) => Open,
+                (Pending, _) | (_, Pending) => Pending,
+                (Expired, Expired) => Expired,
+                (Canceled, _) | (_, Canceled) => Canceled,
+                _ => Unknown,
+            },
+        }
+    }
+
+    /// whether any leg can still be executed
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        matches!(self.overall(), OrderStatus::Open | OrderStatus::PartiallyExecuted | OrderStatus::Pending)
+    }
+
+    /// the leg, that was executed first
+    pub fn executed_leg(&self) -> Option<Leg> {
+        let executed = |status| matches!(status, OrderStatus::Executed | OrderStatus::PartiallyExecuted);
+        match (executed(self.first), executed(self.second)) {
+            (true, _) => Some(Leg::First),
+            (false, true) => Some(Leg::Second),
+            (false, false) => None,
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use chrono::{DateTime, Utc};
 use pecunia::price::Price;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::order::ExecutionId;
+use crate::types::quantity::Quantity;
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
 pub struct Execution {
     #[serde(with = "ExecutionId")]
     #[serde(rename = "executionId")]
@@ -14,10 +16,8 @@ pub struct Execution {
     /// first execution = 1
     #[serde(rename = "executionNumber")]
     rank: u64,
-    /// for explanation, why this is a price, see [RawSingleOrder::quantity](RawSingleOrder::quantity)
     #[serde(rename = "executedQuantity")]
-    #[serde(with = "crate::serde::amount_value::price")]
-    quantity: Price,
+    quantity: Quantity,
     #[serde(rename = "executionPrice")]
     #[serde(with = "crate::serde::amount_value::price")]
     price: Price,
@@ -0,0 +1,111 @@
+use pecunia::prelude::*;
+use wall_street::order::OrderStatus;
+
+use crate::types::order::{Order, RawOrder, RawSingleOrder};
+use crate::types::order::combination::Leg;
+use crate::types::order::execution::Execution;
+
+/// The result of waiting for an order (see
+/// [`wait_for_order`](crate::interface::ApiClient::wait_for_order)).
+#[derive(Debug, PartialEq, getset::Getters, getset::CopyGetters)]
+pub struct OrderFill<'d> {
+    #[getset(get = "pub")]
+    order: Order<'d>,
+    /// the status of the order, when the waiting ended
+    #[getset(get_copy = "pub")]
+    status: OrderStatus,
+    /// the fills of the legs, a single order has only the first leg
+    #[getset(get = "pub")]
+    legs: Vec<LegFill>,
+    /// whether the order was not finished in time
+    #[getset(get_copy = "pub")]
+    timed_out: bool,
+    /// whether the open remainder was canceled after the timeout
+    #[getset(get_copy = "pub")]
+    canceled_remainder: bool,
+}
+
+/// The executions of one leg of an order.
+///
+/// The legs of a combination order are reported separately, since the legs of a next order
+/// usually have opposite directions.
+#[derive(Debug, PartialEq, getset::Getters, getset::CopyGetters)]
+pub struct LegFill {
+    #[getset(get_copy = "pub")]
+    leg: Leg,
+    #[getset(get = "pub")]
+    executions: Vec<Execution>,
+    /// the volume weighted average price of the executions of the leg
+    #[getset(get = "pub")]
+    average_price: Option<Price>,
+}
+
+impl<'d> OrderFill<'d> {
+    pub(crate) fn new(order: Order<'d>, timed_out: bool, canceled_remainder: bool) -> Self {
+        let legs = match order.raw() {
+            RawOrder::SingleOrder(order) => vec![LegFill::new(Leg::First, order)],
+            RawOrder::CombinationOrder(order) => vec![
+                LegFill::new(Leg::First, &order.sub_orders.0),
+                LegFill::new(Leg::Second, &order.sub_orders.1),
+            ],
+        };
+
+        Self {
+            status: order.status(),
+            order,
+            legs,
+            timed_out,
+            canceled_remainder,
+        }
+    }
+
+    #[inline(always)]
+    pub fn leg(&self, leg: Leg) -> Option<&LegFill> {
+        self.legs.iter().find(|fill| fill.leg == leg)
+    }
+
+    /// the legs, that were (partially) executed
+    pub fn executed_legs(&self) -> impl Iterator<Item=&LegFill> {
+        self.legs.iter().filter(|fill| !fill.executions.is_empty())
+    }
+
+    #[inline(always)]
+    pub fn into_order(self) -> Order<'d> {
+        self.order
+    }
+}
+
+impl LegFill {
+    fn new(leg: Leg, order: &RawSingleOrder) -> Self {
+        Self {
+            leg,
+            average_price: volume_weighted_average_price(&order.executions),
+            executions: order.executions.clone(),
+        }
+    }
+}
+
+/// whether the order can not change anymore
+#[inline(always)]
+pub(crate) fn is_final(status: OrderStatus) -> bool {
+    !matches!(status, OrderStatus::Open | OrderStatus::PartiallyExecuted | OrderStatus::Pending | OrderStatus::Unknown)
+}
+
+fn volume_weighted_average_price(executions: &[Execution]) -> Option<Price> {
+    let currency = executions.first()?.price().currency();
+
+    let (volume, quantity) = executions
+        .iter()
+        .fold((0.0, 0.0), |(volume, quantity), execution| {
+            let execution_quantity = execution.quantity().units_at(execution.price()).unwrap_or(0.0);
+            (
+                volume + f64::from(execution.price().raw_price()) * execution_quantity,
+                quantity + execution_quantity,
+            )
+        });
+
+    match quantity > 0.0 {
+        true => Some(Price::new(volume / quantity, currency)),
+        false => None
+    }
+}
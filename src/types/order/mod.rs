@@ -3,17 +3,27 @@ use pecunia::prelude::*;
 use serde::{Deserialize, Serialize};
 use wall_street::order::{AuctionType, OrderDirection, OrderStatus, OrderType, OrderTypeExtension, OrderValidity};
 
+use combination::{CombinationOrderStatus, Leg};
 use execution::Execution;
 
 use crate::types::deposit::ComdirectDeposit;
 use crate::types::instrument::InstrumentId;
 use crate::types::market_place::MarketPlaceId;
+use crate::types::quantity::Quantity;
+use crate::types::response_value::ResponseValue;
 
+pub mod combination;
 pub mod execution;
+pub mod fill;
 pub mod order_change;
 pub mod order_outline;
+pub mod prepared;
+pub mod typed_outline;
+pub mod validation;
+pub mod watcher;
 
 new_type_ids!(
+    #[derive(Hash)]
     pub struct OrderId
     pub struct ExecutionId
 );
@@ -25,25 +35,25 @@ pub struct Order<'d> {
     raw: RawOrder,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
 pub enum RawOrder {
     CombinationOrder(RawCombinationOrder),
     SingleOrder(RawSingleOrder),
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, getset::Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
 #[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawCombinationOrder {
     #[serde(rename = "orderId")]
     id: OrderId,
-    #[serde(with = "crate::serde::order_type")]
-    order_type: OrderType,
+    #[serde(with = "crate::serde::order_type::response_value")]
+    order_type: ResponseValue<OrderType>,
     sub_orders: (RawSingleOrder, RawSingleOrder),
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, getset::Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
 #[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawSingleOrder {
@@ -51,27 +61,25 @@ pub struct RawSingleOrder {
     id: OrderId,
     instrument_id: InstrumentId,
 
-    #[serde(with = "crate::serde::order_type")]
-    order_type: OrderType,
+    #[serde(with = "crate::serde::order_type::response_value")]
+    order_type: ResponseValue<OrderType>,
     #[serde(default)]
     #[serde(rename = "limitExtension")]
-    #[serde(with = "crate::serde::order_type_extension")]
-    order_type_extension: OrderTypeExtension,
+    #[serde(with = "crate::serde::order_type_extension::response_value")]
+    #[serde(skip_serializing_if = "crate::serde::order_type_extension::response_value::is_none")]
+    order_type_extension: ResponseValue<OrderTypeExtension>,
     #[serde(rename = "side")]
     #[serde(with = "crate::serde::order_direction")]
     direction: OrderDirection,
-    // FIXME: #[serde(flatten)] and #[serde(default)] conflict in the current serde version 
-    #[serde(skip_deserializing)]
-    // github issue: https://github.com/serde-rs/serde/issues/1626
-    // therefore this will fail for any order without an explicit order validity
+    /// orders without an explicit validity are valid for the current day
     #[serde(flatten)]
-    #[serde(default = "order_validity_default")]
-    #[serde(with = "crate::serde::order_validity")]
-    validity: OrderValidity,
+    #[serde(with = "crate::serde::order_validity::flattened")]
+    validity: ResponseValue<OrderValidity>,
     #[serde(default)]
     #[serde(rename = "tradingRestriction")]
-    #[serde(with = "crate::serde::auction_type")]
-    auction: AuctionType,
+    #[serde(with = "crate::serde::auction_type::response_value")]
+    #[serde(skip_serializing_if = "crate::serde::auction_type::response_value::is_all")]
+    auction: ResponseValue<AuctionType>,
     #[serde(rename = "orderStatus")]
     #[serde(with = "crate::serde::order_status")]
     status: OrderStatus,
@@ -95,35 +103,25 @@ pub struct RawSingleOrder {
     #[serde(rename = "bestEx")]
     best_execution: bool,
 
-    /// even though the quantity most of the time is just an amount with no currency (NotACurrency), there are cases,
-    /// where the quantity is represented by a valid price. This happens when for savings plan (These orders are 
-    /// expected to buy a variable amount of stocks for a fixed price) order or partially executed orders.
-    #[serde(with = "crate::serde::amount_value::price")]
-    quantity: Price,
-    /// for explanation, why this is a price, see [quantity](RawSingleOrder::quantity)
+    /// a number of units, or the amount of a savings plan order (see [`Quantity`])
+    quantity: Quantity,
     #[serde(default)]
     #[serde(rename = "openQuantity")]
-    #[serde(with = "crate::serde::amount_value::price::option")]
-    open: Option<Price>,
-    /// for explanation, why this is a price, see [quantity](RawSingleOrder::quantity)
+    open: Option<Quantity>,
     #[serde(default)]
     #[serde(rename = "cancelledQuantity")]
-    #[serde(with = "crate::serde::amount_value::price::option")]
-    canceled: Option<Price>,
-    /// for explanation, why this is a price, see [quantity](RawSingleOrder::quantity)
+    canceled: Option<Quantity>,
     #[serde(default)]
     #[serde(rename = "executedQuantity")]
-    #[serde(with = "crate::serde::amount_value::price::option")]
-    executed: Option<Price>,
+    executed: Option<Quantity>,
     executions: Vec<Execution>,
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-pub enum ComdirectOrderValidityType {
-    #[serde(rename = "GFD")]
-    GoodForDay,
-    #[serde(rename = "GTD")]
-    GoodTillDate,
+response_enum! {
+    pub enum ComdirectOrderValidityType {
+        GoodForDay = "GFD",
+        GoodTillDate = "GTD",
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize, PartialEq, getset::Setters)]
@@ -169,6 +167,15 @@ impl<'d> Order<'d> {
         }
     }
 
+    /// the instrument of the order (for combination orders both sub orders share the instrument)
+    #[inline(always)]
+    pub fn instrument_id(&self) -> &InstrumentId {
+        match &self.raw {
+            RawOrder::SingleOrder(raw) => &raw.instrument_id,
+            RawOrder::CombinationOrder(raw) => &raw.sub_orders.0.instrument_id
+        }
+    }
+
     #[inline(always)]
     pub fn status0(&self) -> OrderStatus {
         use RawOrder::*;
@@ -177,6 +184,41 @@ impl<'d> Order<'d> {
             CombinationOrder(ref order) => order.sub_orders.0.status
         }
     }
+
+    /// the status of the second leg of a combination order
+    #[inline(always)]
+    pub fn status1(&self) -> Option<OrderStatus> {
+        use RawOrder::*;
+        match self.raw {
+            SingleOrder(_) => None,
+            CombinationOrder(ref order) => Some(order.sub_orders.1.status)
+        }
+    }
+
+    /// the status of the order, that takes both legs of combination orders into account
+    #[inline(always)]
+    pub fn status(&self) -> OrderStatus {
+        use RawOrder::*;
+        match self.raw {
+            SingleOrder(ref order) => order.status,
+            CombinationOrder(ref order) => order.status().overall()
+        }
+    }
+}
+
+impl RawCombinationOrder {
+    #[inline(always)]
+    pub fn status(&self) -> CombinationOrderStatus {
+        CombinationOrderStatus::new(self.order_type.copied(), self.sub_orders.0.status, self.sub_orders.1.status)
+    }
+
+    #[inline(always)]
+    pub fn leg(&self, leg: Leg) -> &RawSingleOrder {
+        match leg {
+            Leg::First => &self.sub_orders.0,
+            Leg::Second => &self.sub_orders.1,
+        }
+    }
 }
 
 impl RawOrder {
@@ -206,11 +248,7 @@ impl RawSingleOrder {
     update_limit!(fn update_absolute_trailing_limit, absolute_trailing_limit);
     update_limit!(fn update_relative_trailing_limit, relative_trailing_limit: Percent);
 
-    pub(crate) fn update_validity(&mut self, new_validity: OrderValidity) -> OrderValidity {
-        std::mem::replace(&mut self.validity, new_validity)
+    pub(crate) fn update_validity(&mut self, new_validity: OrderValidity) -> ResponseValue<OrderValidity> {
+        std::mem::replace(&mut self.validity, ResponseValue::Known(new_validity))
     }
 }
-
-fn order_validity_default() -> OrderValidity {
-    OrderValidity::OneDay
-}
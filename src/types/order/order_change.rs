@@ -3,6 +3,8 @@ use serde::{Serialize, Serializer};
 use wall_street::order::OrderValidity;
 
 use crate::types::order::{Order, OrderId, RawOrder, RawSingleOrder};
+use crate::types::order::combination::Leg;
+use crate::types::trading_calendar::resolve_validity;
 
 pub(crate) enum OrderChangeValidation<'o, 'd, 'oc> {
     Change(&'oc OrderChange<'o>),
@@ -86,6 +88,15 @@ impl<'o> OrderChange<'o> {
         Self::from_raw_single_order(raw_single_order)
     }
 
+    /// Changes one leg of a combination order.
+    /// For single orders the order itself is changed, regardless of the leg.
+    pub fn from_leg(order: &'o mut Order<'_>, leg: Leg) -> Self {
+        match leg {
+            Leg::First => Self::from_order0(order),
+            Leg::Second => Self::from_order1(order),
+        }
+    }
+
     fn from_raw_single_order(raw_single_order: &'o mut RawSingleOrder) -> Self {
         Self {
             raw_single_order,
@@ -107,7 +118,7 @@ impl<'o> OrderChange<'o> {
         self.relative_trailing_limit
             .map(|limit| self.raw_single_order.relative_trailing_limit = Some(limit));
         self.validity
-            .map(|validity| self.raw_single_order.validity = validity);
+            .map(|validity| self.raw_single_order.validity = validity.into());
     }
 
     #[inline(always)]
@@ -115,13 +126,24 @@ impl<'o> OrderChange<'o> {
         &self.raw_single_order.id
     }
 
+    #[inline(always)]
+    pub(crate) fn has_infinite_validity(&self) -> bool {
+        self.validity == Some(OrderValidity::Infinite)
+    }
+
     option_builder_fn!(
         pub fn limit(Price)
         pub fn trigger_limit(Price)
         pub fn absolute_trailing_limit(Price)
         pub fn relative_trailing_limit(Percent)
-        pub fn validity(OrderValidity)
     );
+
+    /// Relative validities are resolved to a `GTD` date right away, with the current date in
+    /// Europe/Berlin (see [`comdirect_validity`](crate::types::trading_calendar::comdirect_validity)).
+    pub fn validity(mut self, validity: OrderValidity) -> Self {
+        self.validity = Some(resolve_validity(validity));
+        self
+    }
 }
 
 pub(crate) fn serialize_order_as_id<S>(order: &RawSingleOrder, serializer: S) -> Result<S::Ok, S::Error>
@@ -1,12 +1,17 @@
+use std::sync::Arc;
+
 use derive_builder::Builder;
 use getset::{Getters, Setters};
 use pecunia::prelude::*;
 use serde::Serialize;
 use wall_street::order::{AuctionType, OrderDirection, OrderType, OrderTypeExtension, OrderValidity};
 
+use crate::error::Error;
 use crate::types::deposit::ComdirectDeposit;
 use crate::types::instrument::InstrumentId;
 use crate::types::market_place::MarketPlaceId;
+use crate::types::order::combination::Leg;
+use crate::types::trading_calendar::resolve_validity;
 
 #[derive(Clone, Debug, Serialize, PartialEq)]
 #[serde(untagged)]
@@ -20,6 +25,9 @@ pub enum OrderOutline<'d, 'i, 'm> {
 #[getset(set = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawCombinationOrderOutline<'d, 'i, 'm> {
+    #[serde(rename = "depotId")]
+    #[serde(serialize_with = "crate::serde::serialize_deposit_as_id")]
+    deposit: &'d ComdirectDeposit,
     #[serde(with = "crate::serde::order_type")]
     order_type: OrderType,
     sub_orders: (RawSingleOrderOutline<'d, 'i, 'm>, RawSingleOrderOutline<'d, 'i, 'm>),
@@ -27,63 +35,76 @@ pub struct RawCombinationOrderOutline<'d, 'i, 'm> {
 
 #[derive(Clone, Debug, Serialize, PartialEq, Getters, Setters, Builder)]
 #[getset(get = "pub")]
-#[getset(set = "pub")]
 #[builder(setter(strip_option))]
 #[serde(rename_all = "camelCase")]
 pub struct RawSingleOrderOutline<'d, 'i, 'm> {
+    #[getset(set = "pub")]
     #[serde(rename = "depotId")]
     #[serde(serialize_with = "crate::serde::serialize_deposit_as_id")]
     deposit: &'d ComdirectDeposit,
+    #[getset(set = "pub")]
     // todo: is this really optional?
     #[serde(skip_serializing_if = "Option::is_none")]
     instrument_id: Option<&'i InstrumentId>,
+    #[getset(set = "pub")]
     #[serde(rename = "venueId")]
     market_place_id: &'m MarketPlaceId,
 
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(with = "crate::serde::order_type")]
     order_type: OrderType,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(rename = "limitExtension")]
     #[serde(with = "crate::serde::order_type_extension")]
     #[serde(skip_serializing_if = "OrderTypeExtension::is_none")]
     order_type_extension: OrderTypeExtension,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(rename = "side")]
     #[serde(with = "crate::serde::order_direction")]
     direction: OrderDirection,
     #[serde(flatten)]
     #[builder(default)]
+    #[builder(setter(custom))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(with = "crate::serde::order_validity::option")]
     validity: Option<OrderValidity>,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(rename = "tradingRestriction")]
     #[serde(with = "crate::serde::auction_type")]
     #[serde(skip_serializing_if = "AuctionType::is_all")]
     auction: AuctionType,
 
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(with = "crate::serde::amount_value::price::option")]
     limit: Option<Price>,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(with = "crate::serde::amount_value::price::option")]
     trigger_limit: Option<Price>,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(rename = "trailingLimitDistAbs")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(with = "crate::serde::amount_value::price::option")]
     absolute_trailing_limit: Option<Price>,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(rename = "trailingLimitDistRel")]
     #[serde(skip_serializing_if = "Option::is_none")]
     relative_trailing_limit: Option<Percent>,
+    #[getset(set = "pub")]
     #[builder(default)]
     #[serde(rename = "bestEx")]
     best_execution: bool,
 
+    #[getset(set = "pub")]
     #[serde(with = "crate::serde::amount_value::quantity")]
     quantity: F64,
 }
@@ -92,19 +113,247 @@ impl<'d> OrderOutline<'d, '_, '_> {
     pub fn deposit(&self) -> &'d ComdirectDeposit {
         match self {
             OrderOutline::SingleOrder(order) => order.deposit,
-            OrderOutline::CombinationOrder(order) => order.sub_orders.0.deposit
+            OrderOutline::CombinationOrder(order) => order.deposit
+        }
+    }
+
+    pub(crate) fn has_infinite_validity(&self) -> bool {
+        let is_infinite = |order: &RawSingleOrderOutline| order.validity == Some(OrderValidity::Infinite);
+        match self {
+            OrderOutline::SingleOrder(order) => is_infinite(order),
+            OrderOutline::CombinationOrder(order) => is_infinite(&order.sub_orders.0) || is_infinite(&order.sub_orders.1)
         }
     }
 }
 
-impl RawCombinationOrderOutline<'_, '_, '_> {
-    pub fn builder<'d, 'i, 'm>() -> RawCombinationOrderOutlineBuilder<'d, 'i, 'm> {
+impl<'d, 'i, 'm> RawCombinationOrderOutline<'d, 'i, 'm> {
+    pub fn builder() -> RawCombinationOrderOutlineBuilder<'d, 'i, 'm> {
         RawCombinationOrderOutlineBuilder::default()
     }
+
+    pub(crate) fn new(
+        order_type: OrderType,
+        first: RawSingleOrderOutline<'d, 'i, 'm>,
+        second: RawSingleOrderOutline<'d, 'i, 'm>,
+    ) -> Self {
+        Self { deposit: first.deposit, order_type, sub_orders: (first, second) }
+    }
+
+    #[inline(always)]
+    pub fn leg(&self, leg: Leg) -> &RawSingleOrderOutline<'d, 'i, 'm> {
+        match leg {
+            Leg::First => &self.sub_orders.0,
+            Leg::Second => &self.sub_orders.1,
+        }
+    }
 }
 
-impl RawSingleOrderOutline<'_, '_, '_> {
-    pub fn builder<'d, 'i, 'm>() -> RawSingleOrderOutlineBuilder<'d, 'i, 'm> {
+impl<'d, 'i, 'm> RawSingleOrderOutline<'d, 'i, 'm> {
+    pub fn builder() -> RawSingleOrderOutlineBuilder<'d, 'i, 'm> {
         RawSingleOrderOutlineBuilder::default()
     }
+
+    /// Relative validities are resolved to a `GTD` date right away, with the current date in
+    /// Europe/Berlin (see [`comdirect_validity`](crate::types::trading_calendar::comdirect_validity)),
+    /// so the validated and the placed outline are the same.
+    pub fn set_validity(&mut self, validity: Option<OrderValidity>) -> &mut Self {
+        self.validity = validity.map(resolve_validity);
+        self
+    }
+
+    /// creates an outline without any limits, that is valid for the default validity
+    pub(crate) fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        order_type: OrderType,
+        direction: OrderDirection,
+        quantity: F64,
+    ) -> Self {
+        Self {
+            deposit,
+            instrument_id: Some(instrument_id),
+            market_place_id,
+            order_type,
+            order_type_extension: OrderTypeExtension::None,
+            direction,
+            validity: None,
+            auction: AuctionType::All,
+            limit: None,
+            trigger_limit: None,
+            absolute_trailing_limit: None,
+            relative_trailing_limit: None,
+            best_execution: false,
+            quantity,
+        }
+    }
+}
+
+impl RawSingleOrderOutlineBuilder<'_, '_, '_> {
+    /// like [`RawSingleOrderOutline::set_validity`]
+    pub fn validity(&mut self, validity: OrderValidity) -> &mut Self {
+        self.validity = Some(Some(resolve_validity(validity)));
+        self
+    }
+}
+
+/// Lifetime-free variant of [`OrderOutline`], that shares the deposit through an [`Arc`]
+/// and owns the instrument and market place ids (see [`owned`](crate::types::owned)).
+#[derive(Clone, Debug, PartialEq)]
+pub enum OwnedOrderOutline {
+    CombinationOrder(OwnedCombinationOrderOutline),
+    SingleOrder(OwnedSingleOrderOutline),
+}
+
+#[derive(Clone, Debug, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct OwnedCombinationOrderOutline {
+    deposit: Arc<ComdirectDeposit>,
+    order_type: OrderType,
+    sub_orders: (OwnedSingleOrderOutline, OwnedSingleOrderOutline),
+}
+
+#[derive(Clone, Debug, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct OwnedSingleOrderOutline {
+    deposit: Arc<ComdirectDeposit>,
+    instrument_id: Option<InstrumentId>,
+    market_place_id: MarketPlaceId,
+
+    order_type: OrderType,
+    order_type_extension: OrderTypeExtension,
+    direction: OrderDirection,
+    validity: Option<OrderValidity>,
+    auction: AuctionType,
+
+    limit: Option<Price>,
+    trigger_limit: Option<Price>,
+    absolute_trailing_limit: Option<Price>,
+    relative_trailing_limit: Option<Percent>,
+    best_execution: bool,
+
+    quantity: F64,
+}
+
+impl OwnedOrderOutline {
+    /// Creates the owned outline, that shares the given deposit.
+    /// Fails, if the deposit is not the one of the outline.
+    pub fn with_deposit(outline: OrderOutline, deposit: Arc<ComdirectDeposit>) -> Result<Self, Error> {
+        if outline.deposit().id() != deposit.id() {
+            return Err(Error::DifferentDeposit);
+        }
+        Ok(Self::from_parts(outline, deposit))
+    }
+
+    #[inline(always)]
+    pub fn deposit(&self) -> &Arc<ComdirectDeposit> {
+        match self {
+            OwnedOrderOutline::SingleOrder(order) => &order.deposit,
+            OwnedOrderOutline::CombinationOrder(order) => &order.deposit
+        }
+    }
+
+    /// creates a borrowed view, that can be used with the borrowing api
+    pub fn to_borrowed(&self) -> OrderOutline<'_, '_, '_> {
+        match self {
+            OwnedOrderOutline::SingleOrder(order) => OrderOutline::SingleOrder(order.to_borrowed()),
+            OwnedOrderOutline::CombinationOrder(order) => OrderOutline::CombinationOrder(order.to_borrowed()),
+        }
+    }
+
+    fn from_parts(outline: OrderOutline, deposit: Arc<ComdirectDeposit>) -> Self {
+        match outline {
+            OrderOutline::SingleOrder(order) => {
+                OwnedOrderOutline::SingleOrder(OwnedSingleOrderOutline::from_parts(order, deposit))
+            }
+            OrderOutline::CombinationOrder(order) => {
+                let (first, second) = order.sub_orders;
+                OwnedOrderOutline::CombinationOrder(OwnedCombinationOrderOutline {
+                    order_type: order.order_type,
+                    sub_orders: (
+                        OwnedSingleOrderOutline::from_parts(first, Arc::clone(&deposit)),
+                        OwnedSingleOrderOutline::from_parts(second, Arc::clone(&deposit)),
+                    ),
+                    deposit,
+                })
+            }
+        }
+    }
+}
+
+/// clones the deposit into a new [`Arc`]; use `with_deposit` to share an existing one
+impl From<OrderOutline<'_, '_, '_>> for OwnedOrderOutline {
+    fn from(outline: OrderOutline) -> Self {
+        let deposit = Arc::new(outline.deposit().clone());
+        Self::from_parts(outline, deposit)
+    }
+}
+
+impl OwnedCombinationOrderOutline {
+    /// creates a borrowed view, that can be used with the borrowing api
+    pub fn to_borrowed(&self) -> RawCombinationOrderOutline<'_, '_, '_> {
+        RawCombinationOrderOutline {
+            deposit: &self.deposit,
+            order_type: self.order_type,
+            sub_orders: (self.sub_orders.0.to_borrowed(), self.sub_orders.1.to_borrowed()),
+        }
+    }
+}
+
+impl OwnedSingleOrderOutline {
+    /// Creates the owned outline, that shares the given deposit.
+    /// Fails, if the deposit is not the one of the outline.
+    pub fn with_deposit(outline: RawSingleOrderOutline, deposit: Arc<ComdirectDeposit>) -> Result<Self, Error> {
+        if outline.deposit.id() != deposit.id() {
+            return Err(Error::DifferentDeposit);
+        }
+        Ok(Self::from_parts(outline, deposit))
+    }
+
+    /// creates a borrowed view, that can be used with the borrowing api
+    pub fn to_borrowed(&self) -> RawSingleOrderOutline<'_, '_, '_> {
+        RawSingleOrderOutline {
+            deposit: &self.deposit,
+            instrument_id: self.instrument_id.as_ref(),
+            market_place_id: &self.market_place_id,
+            order_type: self.order_type,
+            order_type_extension: self.order_type_extension,
+            direction: self.direction,
+            validity: self.validity,
+            auction: self.auction,
+            limit: self.limit.clone(),
+            trigger_limit: self.trigger_limit.clone(),
+            absolute_trailing_limit: self.absolute_trailing_limit.clone(),
+            relative_trailing_limit: self.relative_trailing_limit.clone(),
+            best_execution: self.best_execution,
+            quantity: self.quantity,
+        }
+    }
+
+    fn from_parts(outline: RawSingleOrderOutline, deposit: Arc<ComdirectDeposit>) -> Self {
+        Self {
+            deposit,
+            instrument_id: outline.instrument_id.cloned(),
+            market_place_id: outline.market_place_id.clone(),
+            order_type: outline.order_type,
+            order_type_extension: outline.order_type_extension,
+            direction: outline.direction,
+            validity: outline.validity,
+            auction: outline.auction,
+            limit: outline.limit,
+            trigger_limit: outline.trigger_limit,
+            absolute_trailing_limit: outline.absolute_trailing_limit,
+            relative_trailing_limit: outline.relative_trailing_limit,
+            best_execution: outline.best_execution,
+            quantity: outline.quantity,
+        }
+    }
+}
+
+/// clones the deposit into a new [`Arc`]; use `with_deposit` to share an existing one
+impl From<RawSingleOrderOutline<'_, '_, '_>> for OwnedSingleOrderOutline {
+    fn from(outline: RawSingleOrderOutline) -> Self {
+        let deposit = Arc::new(outline.deposit.clone());
+        Self::from_parts(outline, deposit)
+    }
 }
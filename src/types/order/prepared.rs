@@ -0,0 +1,168 @@
+//! Orders, order changes, order deletions and quote orders, that are validated by comdirect
+//! but not yet sent.
+//!
+//! Preparing runs the validation of comdirect and holds its [`TanChallenge`], so the
+//! action can be inspected (i.e. with the ex-ante cost indication) before it is committed
+//! or aborted. Aborting does not need a request, since a validation does not reserve anything.
+//! If the TAN is not free, the user is asked for the TAN while committing.
+
+use std::result::Result as StdResult;
+
+use crate::error::Error;
+use crate::interface::ApiClient;
+use crate::session::tan::TanChallenge;
+use crate::types::cost_indication::{CostIndication, RawCostIndication};
+use crate::types::order::Order;
+use crate::types::order::order_change::{OrderChange, OrderChangeAction, OrderChangeValidation};
+use crate::types::order::order_outline::OrderOutline;
+use crate::types::quote::order_outline::QuoteOrderOutline;
+
+type Result<T> = StdResult<T, Error>;
+
+#[derive(getset::Getters)]
+pub struct PreparedOrder<'c, 'o, 'd, 'i, 'm> {
+    client: &'c ApiClient,
+    #[getset(get = "pub")]
+    outline: &'o OrderOutline<'d, 'i, 'm>,
+    /// the result of the validation
+    #[getset(get = "pub")]
+    tan_challenge: TanChallenge,
+    /// the ex-ante cost indication, if it was requested
+    #[getset(get = "pub")]
+    cost_indication: Option<CostIndication<'o, 'd, 'i, 'm>>,
+}
+
+#[derive(getset::Getters)]
+pub struct PreparedOrderChange<'c, 'o> {
+    client: &'c ApiClient,
+    #[getset(get = "pub")]
+    order_change: OrderChange<'o>,
+    /// the result of the validation
+    #[getset(get = "pub")]
+    tan_challenge: TanChallenge,
+    /// the ex-ante cost indication, if it was requested
+    #[getset(get = "pub")]
+    cost_indication: Option<RawCostIndication>,
+}
+
+#[derive(getset::Getters)]
+pub struct PreparedOrderDeletion<'c, 'd> {
+    client: &'c ApiClient,
+    #[getset(get = "pub")]
+    order: Order<'d>,
+    /// the result of the validation
+    #[getset(get = "pub")]
+    tan_challenge: TanChallenge,
+}
+
+#[derive(getset::Getters)]
+pub struct PreparedQuoteOrder<'c, 'd> {
+    client: &'c ApiClient,
+    #[getset(get = "pub")]
+    outline: QuoteOrderOutline<'d>,
+    /// the result of the validation
+    #[getset(get = "pub")]
+    tan_challenge: TanChallenge,
+    /// the ex-ante cost indication, if it was requested
+    #[getset(get = "pub")]
+    cost_indication: Option<RawCostIndication>,
+}
+
+impl<'c, 'o, 'd, 'i, 'm> PreparedOrder<'c, 'o, 'd, 'i, 'm> {
+    pub(crate) fn new(client: &'c ApiClient, outline: &'o OrderOutline<'d, 'i, 'm>, tan_challenge: TanChallenge) -> Self {
+        Self { client, outline, tan_challenge, cost_indication: None }
+    }
+
+    /// Requests the ex-ante cost indication, if it was not requested before.
+    pub fn request_cost_indication(&mut self) -> Result<&CostIndication<'o, 'd, 'i, 'm>> {
+        if self.cost_indication.is_none() {
+            let raw = self.client._order_cost_indication(self.outline)?;
+            self.cost_indication = Some(CostIndication::from_raw(raw, self.outline));
+        }
+        Ok(self.cost_indication.as_ref().unwrap())
+    }
+
+    /// Places the order.
+    pub fn commit(self) -> Result<Order<'d>> {
+        self.client.place_order_outline(self.outline, self.tan_challenge)
+    }
+
+    /// Discards the validation without placing the order.
+    #[inline(always)]
+    pub fn abort(self) {}
+}
+
+impl<'c, 'o> PreparedOrderChange<'c, 'o> {
+    pub(crate) fn new(client: &'c ApiClient, order_change: OrderChange<'o>, tan_challenge: TanChallenge) -> Self {
+        Self { client, order_change, tan_challenge, cost_indication: None }
+    }
+
+    /// Requests the ex-ante cost indication, if it was not requested before.
+    pub fn request_cost_indication(&mut self) -> Result<&RawCostIndication> {
+        if self.cost_indication.is_none() {
+            let validation = OrderChangeValidation::Change(&self.order_change);
+            let raw = self.client._order_change_cost_indication(validation)?.into_raw();
+            self.cost_indication = Some(raw);
+        }
+        Ok(self.cost_indication.as_ref().unwrap())
+    }
+
+    /// Changes the order. On success, the changes are applied to the order as well.
+    pub fn commit(self) -> Result<()> {
+        let action = OrderChangeAction::Change(self.order_change);
+        self.client._change_order(action, self.tan_challenge)
+    }
+
+    /// Discards the validation and returns the unsent change.
+    #[inline(always)]
+    pub fn abort(self) -> OrderChange<'o> {
+        self.order_change
+    }
+}
+
+impl<'c, 'd> PreparedOrderDeletion<'c, 'd> {
+    pub(crate) fn new(client: &'c ApiClient, order: Order<'d>, tan_challenge: TanChallenge) -> Self {
+        Self { client, order, tan_challenge }
+    }
+
+    /// Deletes the order. On failure, the order is returned with the error.
+    pub fn commit(self) -> StdResult<(), (Error, Order<'d>)> {
+        let action = OrderChangeAction::Delete(&self.order);
+        match self.client._change_order(action, self.tan_challenge) {
+            Ok(()) => Ok(()),
+            Err(e) => Err((e, self.order))
+        }
+    }
+
+    /// Discards the validation and returns the order.
+    #[inline(always)]
+    pub fn abort(self) -> Order<'d> {
+        self.order
+    }
+}
+
+impl<'c, 'd> PreparedQuoteOrder<'c, 'd> {
+    pub(crate) fn new(client: &'c ApiClient, outline: QuoteOrderOutline<'d>, tan_challenge: TanChallenge) -> Self {
+        Self { client, outline, tan_challenge, cost_indication: None }
+    }
+
+    /// Requests the ex-ante cost indication, if it was not requested before.
+    pub fn request_cost_indication(&mut self) -> Result<&RawCostIndication> {
+        if self.cost_indication.is_none() {
+            let raw = self.client._order_cost_indication(&self.outline)?;
+            self.cost_indication = Some(raw);
+        }
+        Ok(self.cost_indication.as_ref().unwrap())
+    }
+
+    /// Places the quote order.
+    pub fn commit(self) -> Result<Order<'d>> {
+        self.client.place_quote_order_outline(&self.outline, self.tan_challenge)
+    }
+
+    /// Discards the validation and returns the unsent outline.
+    #[inline(always)]
+    pub fn abort(self) -> QuoteOrderOutline<'d> {
+        self.outline
+    }
+}
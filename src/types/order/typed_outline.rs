@@ -0,0 +1,297 @@
+//! Typed constructors for order outlines.
+//!
+//! Unlike the [`RawSingleOrderOutlineBuilder`], every constructor requires exactly the limits
+//! of its order type, so an outline without a limit or with a superfluous trigger limit
+//! can not be built.
+//!
+//! [`RawSingleOrderOutlineBuilder`]: crate::types::order::order_outline::RawSingleOrderOutlineBuilder
+
+use pecunia::prelude::*;
+use wall_street::order::{AuctionType, OrderDirection, OrderType, OrderTypeExtension, OrderValidity};
+
+use crate::types::deposit::ComdirectDeposit;
+use crate::types::instrument::InstrumentId;
+use crate::types::market_place::MarketPlaceId;
+use crate::types::order::order_outline::{OrderOutline, RawCombinationOrderOutline, RawSingleOrderOutline};
+use crate::types::order::validation::OrderViolation;
+use crate::types::quote::QuoteOutline;
+
+/// the trailing distance of a trailing stop order
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrailingDistance {
+    Absolute(Price),
+    Relative(Percent),
+}
+
+macro_rules! typed_outline {
+    ($($(#[$meta:meta])* pub struct $name:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct $name<'d, 'i, 'm>(RawSingleOrderOutline<'d, 'i, 'm>);
+
+            impl<'d, 'i, 'm> $name<'d, 'i, 'm> {
+                pub fn validity(mut self, validity: OrderValidity) -> Self {
+                    self.0.set_validity(Some(validity));
+                    self
+                }
+
+                pub fn order_type_extension(mut self, order_type_extension: OrderTypeExtension) -> Self {
+                    self.0.set_order_type_extension(order_type_extension);
+                    self
+                }
+
+                pub fn auction(mut self, auction: AuctionType) -> Self {
+                    self.0.set_auction(auction);
+                    self
+                }
+
+                pub fn best_execution(mut self, best_execution: bool) -> Self {
+                    self.0.set_best_execution(best_execution);
+                    self
+                }
+
+                #[inline(always)]
+                pub fn as_raw(&self) -> &RawSingleOrderOutline<'d, 'i, 'm> {
+                    &self.0
+                }
+
+                #[inline(always)]
+                pub fn into_raw(self) -> RawSingleOrderOutline<'d, 'i, 'm> {
+                    self.0
+                }
+            }
+
+            impl<'d, 'i, 'm> From<$name<'d, 'i, 'm>> for RawSingleOrderOutline<'d, 'i, 'm> {
+                fn from(order: $name<'d, 'i, 'm>) -> Self {
+                    order.0
+                }
+            }
+
+            impl<'d, 'i, 'm> From<$name<'d, 'i, 'm>> for OrderOutline<'d, 'i, 'm> {
+                fn from(order: $name<'d, 'i, 'm>) -> Self {
+                    OrderOutline::SingleOrder(order.0)
+                }
+            }
+        )*
+    };
+}
+
+typed_outline! {
+    /// an order, that is executed at the next available price
+    pub struct MarketOrder;
+    /// an order, that is executed at the limit or a better price
+    pub struct LimitOrder;
+    /// an order, that becomes a market order, once the trigger limit is reached
+    pub struct StopMarketOrder;
+    /// an order, that becomes a limit order, once the trigger limit is reached
+    pub struct StopLimitOrder;
+    /// a stop market order, whose trigger limit follows the price at a fixed distance
+    pub struct TrailingStopOrder;
+    /// a stop limit order, whose trigger limit follows the price at a fixed distance
+    pub struct TrailingStopLimitOrder;
+}
+
+impl<'d, 'i, 'm> MarketOrder<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+    ) -> Self {
+        Self(RawSingleOrderOutline::new(deposit, instrument_id, market_place_id, OrderType::Market, direction, quantity))
+    }
+}
+
+impl<'d, 'i, 'm> LimitOrder<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+        limit: Price,
+    ) -> Self {
+        let mut raw = RawSingleOrderOutline::new(deposit, instrument_id, market_place_id, OrderType::Limit, direction, quantity);
+        raw.set_limit(Some(limit));
+        Self(raw)
+    }
+}
+
+impl<'d, 'i, 'm> StopMarketOrder<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+        trigger_limit: Price,
+    ) -> Self {
+        let mut raw = RawSingleOrderOutline::new(deposit, instrument_id, market_place_id, OrderType::StopMarket, direction, quantity);
+        raw.set_trigger_limit(Some(trigger_limit));
+        Self(raw)
+    }
+}
+
+impl<'d, 'i, 'm> StopLimitOrder<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+        trigger_limit: Price,
+        limit: Price,
+    ) -> Self {
+        let mut raw = RawSingleOrderOutline::new(deposit, instrument_id, market_place_id, OrderType::StopLimit, direction, quantity);
+        raw.set_trigger_limit(Some(trigger_limit));
+        raw.set_limit(Some(limit));
+        Self(raw)
+    }
+}
+
+impl<'d, 'i, 'm> TrailingStopOrder<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+        trigger_limit: Price,
+        distance: TrailingDistance,
+    ) -> Self {
+        let mut raw = RawSingleOrderOutline::new(deposit, instrument_id, market_place_id, OrderType::TrailingStopMarket, direction, quantity);
+        raw.set_trigger_limit(Some(trigger_limit));
+        set_trailing_distance(&mut raw, distance);
+        Self(raw)
+    }
+}
+
+impl<'d, 'i, 'm> TrailingStopLimitOrder<'d, 'i, 'm> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+        trigger_limit: Price,
+        limit: Price,
+        distance: TrailingDistance,
+    ) -> Self {
+        let mut raw = RawSingleOrderOutline::new(deposit, instrument_id, market_place_id, OrderType::TrailingStopLimit, direction, quantity);
+        raw.set_trigger_limit(Some(trigger_limit));
+        raw.set_limit(Some(limit));
+        set_trailing_distance(&mut raw, distance);
+        Self(raw)
+    }
+}
+
+#[inline(always)]
+fn set_trailing_distance(raw: &mut RawSingleOrderOutline, distance: TrailingDistance) {
+    match distance {
+        TrailingDistance::Absolute(distance) => raw.set_absolute_trailing_limit(Some(distance)),
+        TrailingDistance::Relative(distance) => raw.set_relative_trailing_limit(Some(distance)),
+    };
+}
+
+/// the stop leg of a one cancels other order
+pub trait StopOrder<'d, 'i, 'm>: Into<RawSingleOrderOutline<'d, 'i, 'm>> {}
+
+impl<'d, 'i, 'm> StopOrder<'d, 'i, 'm> for StopMarketOrder<'d, 'i, 'm> {}
+
+impl<'d, 'i, 'm> StopOrder<'d, 'i, 'm> for StopLimitOrder<'d, 'i, 'm> {}
+
+macro_rules! combination_outline {
+    ($($(#[$meta:meta])* pub struct $name:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct $name<'d, 'i, 'm>(RawCombinationOrderOutline<'d, 'i, 'm>);
+
+            impl<'d, 'i, 'm> $name<'d, 'i, 'm> {
+                fn from_legs(order_type: OrderType, first: RawSingleOrderOutline<'d, 'i, 'm>, second: RawSingleOrderOutline<'d, 'i, 'm>)
+                    -> Result<Self, Vec<OrderViolation>> {
+                    let raw = RawCombinationOrderOutline::new(order_type, first, second);
+                    let violations = raw.leg_violations();
+                    match violations.is_empty() {
+                        true => Ok(Self(raw)),
+                        false => Err(violations)
+                    }
+                }
+
+                #[inline(always)]
+                pub fn as_raw(&self) -> &RawCombinationOrderOutline<'d, 'i, 'm> {
+                    &self.0
+                }
+
+                #[inline(always)]
+                pub fn into_raw(self) -> RawCombinationOrderOutline<'d, 'i, 'm> {
+                    self.0
+                }
+            }
+
+            impl<'d, 'i, 'm> From<$name<'d, 'i, 'm>> for OrderOutline<'d, 'i, 'm> {
+                fn from(order: $name<'d, 'i, 'm>) -> Self {
+                    OrderOutline::CombinationOrder(order.0)
+                }
+            }
+        )*
+    };
+}
+
+combination_outline! {
+    /// a combination of a stop order and a limit order, once one of them is executed,
+    /// the other one is canceled
+    pub struct OneCancelsOtherOrder;
+    /// a combination of two orders, where the second order is only placed,
+    /// once the first order was executed
+    pub struct NextOrder;
+}
+
+impl<'d, 'i, 'm> OneCancelsOtherOrder<'d, 'i, 'm> {
+    /// Both legs need to share the deposit, instrument, market place, direction and quantity.
+    pub fn new<S: StopOrder<'d, 'i, 'm>>(stop: S, limit: LimitOrder<'d, 'i, 'm>) -> Result<Self, Vec<OrderViolation>> {
+        Self::from_legs(OrderType::OneCancelsTheOther, stop.into(), limit.into())
+    }
+}
+
+impl<'d, 'i, 'm> NextOrder<'d, 'i, 'm> {
+    /// Both legs need to share the deposit, instrument and market place.
+    pub fn new<F, N>(first: F, next: N) -> Result<Self, Vec<OrderViolation>>
+        where F: Into<RawSingleOrderOutline<'d, 'i, 'm>>,
+              N: Into<RawSingleOrderOutline<'d, 'i, 'm>> {
+        Self::from_legs(OrderType::OneTriggersTheOther, first.into(), next.into())
+    }
+}
+
+/// A quote order.
+///
+/// Quote orders are not placed from an [`OrderOutline`], but by requesting a quote
+/// (see `ApiClient::get_quote`) first, so a quote order converts into a [`QuoteOutline`].
+#[derive(Clone, Debug)]
+pub struct QuoteOrder<'d, 'i, 'm>(QuoteOutline<'d, 'i, 'm>);
+
+impl<'d, 'i, 'm> QuoteOrder<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+    ) -> Self {
+        Self(QuoteOutline::new(deposit, instrument_id, market_place_id, direction, quantity))
+    }
+
+    #[inline(always)]
+    pub fn as_outline(&self) -> &QuoteOutline<'d, 'i, 'm> {
+        &self.0
+    }
+}
+
+impl<'d, 'i, 'm> From<QuoteOrder<'d, 'i, 'm>> for QuoteOutline<'d, 'i, 'm> {
+    fn from(order: QuoteOrder<'d, 'i, 'm>) -> Self {
+        order.0
+    }
+}
@@ -0,0 +1,238 @@
+//! Local validation of order outlines against the abilities of a market place.
+//!
+//! The validation does not replace the validation of comdirect, but catches the most common
+//! mistakes before any request is sent.
+
+use wall_street::order::{AuctionType, OrderDirection, OrderType, OrderTypeExtension, OrderValidity};
+
+use crate::types::market_place::{MarketPlace, MarketPlaceId, OrderTypeAbilities};
+use crate::types::order::ComdirectOrderValidityType;
+use crate::types::order::combination::Leg;
+use crate::types::order::order_outline::{OrderOutline, RawCombinationOrderOutline, RawSingleOrderOutline};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrderViolation {
+    /// the outline references another market place, than the one it was validated against
+    WrongMarketPlace(MarketPlaceId),
+    MissingInstrument,
+    DirectionNotSupported(OrderDirection),
+    ValidityNotSupported(OrderValidity),
+    OrderTypeNotSupported(OrderType),
+    OrderTypeExtensionNotSupported(OrderTypeExtension),
+    AuctionTypeNotSupported(AuctionType),
+    /// a combination order with a type other than `OneCancelsTheOther` or `OneTriggersTheOther`,
+    /// or a single order with one of these types
+    InvalidCombination(OrderType),
+
+    MissingLimit,
+    MissingTriggerLimit,
+    /// trailing orders need either an absolute or a relative trailing distance
+    MissingTrailingDistance,
+    UnexpectedLimit,
+    UnexpectedTriggerLimit,
+    UnexpectedTrailingDistance,
+    /// both, an absolute and a relative trailing distance, are set
+    AmbiguousTrailingDistance,
+
+    /// the legs of a combination order belong to different deposits
+    DifferentDeposits,
+    /// the legs of a combination order refer to different instruments
+    DifferentInstruments,
+    /// the legs of a combination order are placed at different market places
+    DifferentMarketPlaces,
+    /// the legs of a one cancels other order have different directions
+    DifferentDirections,
+    /// the legs of a one cancels other order have different quantities
+    DifferentQuantities,
+    /// a one cancels other order needs a limit leg and a stop (market or limit) leg
+    InvalidLegOrderTypes(OrderType, OrderType),
+
+    /// a violation of one sub order of a combination order
+    SubOrder(Leg, Box<OrderViolation>),
+}
+
+impl OrderOutline<'_, '_, '_> {
+    /// Checks the outline against the abilities of the market place and returns every violation.
+    pub fn validate(&self, market_place: &MarketPlace) -> Result<(), Vec<OrderViolation>> {
+        let violations = self.violations(market_place);
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(violations)
+        }
+    }
+
+    pub fn violations(&self, market_place: &MarketPlace) -> Vec<OrderViolation> {
+        match self {
+            OrderOutline::SingleOrder(order) => order.violations(market_place),
+            OrderOutline::CombinationOrder(order) => order.violations(market_place),
+        }
+    }
+}
+
+impl RawCombinationOrderOutline<'_, '_, '_> {
+    pub fn violations(&self, market_place: &MarketPlace) -> Vec<OrderViolation> {
+        let mut violations = Vec::new();
+
+        let order_type = *self.order_type();
+        if !is_combination(order_type) {
+            violations.push(OrderViolation::InvalidCombination(order_type));
+        } else if !market_place.order_types().contains_key(&order_type) {
+            violations.push(OrderViolation::OrderTypeNotSupported(order_type));
+        }
+
+        violations.extend(self.leg_violations());
+
+        for &leg in &[Leg::First, Leg::Second] {
+            violations.extend(
+                self.leg(leg)
+                    .violations(market_place)
+                    .into_iter()
+                    .map(|violation| OrderViolation::SubOrder(leg, Box::new(violation)))
+            );
+        }
+
+        violations
+    }
+
+    /// checks, that both legs fit together, without looking at a market place
+    pub fn leg_violations(&self) -> Vec<OrderViolation> {
+        let mut violations = Vec::new();
+        let (first, second) = self.sub_orders();
+
+        if first.deposit() != self.deposit() || second.deposit() != self.deposit() {
+            violations.push(OrderViolation::DifferentDeposits);
+        }
+        if first.instrument_id() != second.instrument_id() {
+            violations.push(OrderViolation::DifferentInstruments);
+        }
+        if first.market_place_id() != second.market_place_id() {
+            violations.push(OrderViolation::DifferentMarketPlaces);
+        }
+
+        if *self.order_type() == OrderType::OneCancelsTheOther {
+            if first.direction() != second.direction() {
+                violations.push(OrderViolation::DifferentDirections);
+            }
+            if first.quantity() != second.quantity() {
+                violations.push(OrderViolation::DifferentQuantities);
+            }
+
+            let is_stop = |order_type| matches!(order_type, OrderType::StopMarket | OrderType::StopLimit);
+            let (first_type, second_type) = (*first.order_type(), *second.order_type());
+            let valid_legs = (first_type == OrderType::Limit && is_stop(second_type))
+                || (is_stop(first_type) && second_type == OrderType::Limit);
+            if !valid_legs {
+                violations.push(OrderViolation::InvalidLegOrderTypes(first_type, second_type));
+            }
+        }
+
+        violations
+    }
+}
+
+impl RawSingleOrderOutline<'_, '_, '_> {
+    pub fn violations(&self, market_place: &MarketPlace) -> Vec<OrderViolation> {
+        let mut violations = Vec::new();
+
+        if *self.market_place_id() != market_place.id() {
+            violations.push(OrderViolation::WrongMarketPlace((*self.market_place_id()).clone()));
+        }
+        if self.instrument_id().is_none() {
+            violations.push(OrderViolation::MissingInstrument);
+        }
+
+        let direction = *self.direction();
+        if !market_place.order_directions().contains(&direction) {
+            violations.push(OrderViolation::DirectionNotSupported(direction));
+        }
+
+        if let Some(validity) = *self.validity() {
+            let supported = validity_type(validity)
+                .map_or(false, |validity_type| market_place.validity_types().contains(&validity_type));
+            if !supported {
+                violations.push(OrderViolation::ValidityNotSupported(validity));
+            }
+        }
+
+        let order_type = *self.order_type();
+        if is_combination(order_type) {
+            violations.push(OrderViolation::InvalidCombination(order_type));
+        } else {
+            match market_place.order_types().get(&order_type) {
+                Some(abilities) => self.abilities_violations(abilities, &mut violations),
+                None => violations.push(OrderViolation::OrderTypeNotSupported(order_type)),
+            }
+        }
+
+        self.limit_violations(order_type, &mut violations);
+
+        violations
+    }
+
+    fn abilities_violations(&self, abilities: &OrderTypeAbilities, violations: &mut Vec<OrderViolation>) {
+        let extension = *self.order_type_extension();
+        if !extension.is_none() && !abilities.order_type_extensions().contains(&extension) {
+            violations.push(OrderViolation::OrderTypeExtensionNotSupported(extension));
+        }
+
+        let auction = *self.auction();
+        if !auction.is_all() && !abilities.auction_types().contains(&auction) {
+            violations.push(OrderViolation::AuctionTypeNotSupported(auction));
+        }
+    }
+
+    /// checks, that exactly the limits, that the order type needs, are set
+    fn limit_violations(&self, order_type: OrderType, violations: &mut Vec<OrderViolation>) {
+        use OrderType::*;
+
+        let (limit, trigger_limit, trailing_distance) = match order_type {
+            Market => (false, false, false),
+            Limit => (true, false, false),
+            StopMarket => (false, true, false),
+            StopLimit => (true, true, false),
+            // the trigger limit is the initial stop, that follows the price (see 11.2.4 and 11.2.5)
+            TrailingStopMarket => (false, true, true),
+            TrailingStopLimit => (true, true, true),
+            // the limit of a quote order is given by the quote
+            Quote => (false, false, false),
+            OneCancelsTheOther | OneTriggersTheOther => return,
+        };
+
+        match (limit, self.limit().is_some()) {
+            (true, false) => violations.push(OrderViolation::MissingLimit),
+            (false, true) => violations.push(OrderViolation::UnexpectedLimit),
+            _ => {}
+        }
+
+        match (trigger_limit, self.trigger_limit().is_some()) {
+            (true, false) => violations.push(OrderViolation::MissingTriggerLimit),
+            (false, true) => violations.push(OrderViolation::UnexpectedTriggerLimit),
+            _ => {}
+        }
+
+        let absolute = self.absolute_trailing_limit().is_some();
+        let relative = self.relative_trailing_limit().is_some();
+        match (trailing_distance, absolute || relative) {
+            (true, false) => violations.push(OrderViolation::MissingTrailingDistance),
+            (false, true) => violations.push(OrderViolation::UnexpectedTrailingDistance),
+            (true, true) if absolute && relative => violations.push(OrderViolation::AmbiguousTrailingDistance),
+            _ => {}
+        }
+    }
+}
+
+#[inline(always)]
+fn is_combination(order_type: OrderType) -> bool {
+    matches!(order_type, OrderType::OneCancelsTheOther | OrderType::OneTriggersTheOther)
+}
+
+/// the comdirect validity type, an order validity is sent as
+fn validity_type(validity: OrderValidity) -> Option<ComdirectOrderValidityType> {
+    match validity {
+        OrderValidity::OneDay => Some(ComdirectOrderValidityType::GoodForDay),
+        // relative validities are sent as good till date
+        OrderValidity::OneWeek | OrderValidity::OneMonth | OrderValidity::OneYear | OrderValidity::TillDate(_) =>
+            Some(ComdirectOrderValidityType::GoodTillDate),
+        OrderValidity::Infinite => None
+    }
+}
@@ -0,0 +1,218 @@
+//! Watches the order books of deposits and emits an [`OrderEvent`] for every change.
+//!
+//! The order books are polled and compared to the previous poll by [`OrderId`]. Combination
+//! orders are watched leg by leg, since every leg has its own id, status and executions.
+
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::thread::sleep;
+use std::time::Duration;
+
+use wall_street::order::OrderStatus;
+
+use crate::error::Error;
+use crate::interface::ApiClient;
+use crate::types::deposit::{ComdirectDeposit, DepositId};
+use crate::types::order::{OrderId, RawOrder, RawSingleOrder};
+use crate::types::order::execution::Execution;
+use crate::types::order::fill::is_final;
+
+/// the poll interval, if not specified otherwise
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+pub struct OrderEvent {
+    deposit_id: DepositId,
+    kind: OrderEventKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrderEventKind {
+    Created(RawSingleOrder),
+    /// the order got new executions, but is not fully executed yet
+    PartiallyExecuted { order: RawSingleOrder, executions: Vec<Execution> },
+    /// the order is fully executed, `executions` contains only the new executions
+    Executed { order: RawSingleOrder, executions: Vec<Execution> },
+    Canceled(RawSingleOrder),
+    Expired(RawSingleOrder),
+    /// any other change, i.e. of the limit or the validity
+    Changed { previous: RawSingleOrder, order: RawSingleOrder },
+    /// The order dropped out of the order book, before it was executed, canceled or expired.
+    /// The order is the last known state. The [`OrderWatcher`] requests the order instead,
+    /// and only emits this event, if the order can not be found anymore.
+    Removed(RawSingleOrder),
+}
+
+/// The last known state of the watched order books.
+///
+/// The first order book of a deposit is taken as it is and does not cause any events.
+#[derive(Clone, Debug, Default)]
+pub struct OrderBookDiff {
+    order_books: HashMap<DepositId, HashMap<OrderId, RawSingleOrder>>,
+}
+
+pub struct OrderWatcher<'c, 'd> {
+    client: &'c ApiClient,
+    deposits: Vec<&'d ComdirectDeposit>,
+    interval: Duration,
+    diff: OrderBookDiff,
+}
+
+impl OrderEventKind {
+    #[inline(always)]
+    pub fn order(&self) -> &RawSingleOrder {
+        use OrderEventKind::*;
+        match self {
+            Created(order) | Canceled(order) | Expired(order) | Removed(order) => order,
+            PartiallyExecuted { order, .. } | Executed { order, .. } | Changed { order, .. } => order,
+        }
+    }
+}
+
+impl OrderBookDiff {
+    /// Replaces the known order book of the deposit and returns the events, that lead
+    /// from the previous to the new order book.
+    /// Orders, that are not final and are missing from the new order book, cause a
+    /// [`Removed`](OrderEventKind::Removed) event.
+    pub fn update(&mut self, deposit_id: &DepositId, orders: Vec<RawOrder>) -> Vec<OrderEvent> {
+        let order_book: HashMap<OrderId, RawSingleOrder> = orders
+            .into_iter()
+            .flat_map(|order| match order {
+                RawOrder::SingleOrder(order) => vec![order],
+                RawOrder::CombinationOrder(order) => vec![order.sub_orders.0, order.sub_orders.1],
+            })
+            .map(|order| (order.id.clone(), order))
+            .collect();
+
+        let previous = match self.order_books.insert(deposit_id.clone(), order_book) {
+            Some(previous) => previous,
+            None => return Vec::new(),
+        };
+
+        let mut events: Vec<OrderEvent> = self.order_books[deposit_id]
+            .values()
+            .filter_map(|order| {
+                let kind = match previous.get(&order.id) {
+                    Some(previous) => Self::event_kind(previous, order)?,
+                    None => OrderEventKind::Created(order.clone()),
+                };
+                Some(OrderEvent { deposit_id: deposit_id.clone(), kind })
+            })
+            .collect();
+
+        let order_book = &self.order_books[deposit_id];
+        events.extend(
+            previous
+                .into_iter()
+                .filter(|(id, order)| !order_book.contains_key(id) && !is_final(order.status))
+                .map(|(_, order)| OrderEvent { deposit_id: deposit_id.clone(), kind: OrderEventKind::Removed(order) })
+        );
+        // the events are emitted in the order the orders were created
+        events.sort_by_key(|event| event.kind.order().creation);
+
+        events
+    }
+
+    fn event_kind(previous: &RawSingleOrder, order: &RawSingleOrder) -> Option<OrderEventKind> {
+        if previous == order {
+            return None;
+        }
+
+        let executions: Vec<Execution> = order.executions
+            .iter()
+            .filter(|execution| !previous.executions.iter().any(|known| known.id() == execution.id()))
+            .cloned()
+            .collect();
+        let status_changed = previous.status != order.status;
+
+        let kind = match order.status {
+            OrderStatus::Executed if status_changed =>
+                OrderEventKind::Executed { order: order.clone(), executions },
+            OrderStatus::Canceled if status_changed => OrderEventKind::Canceled(order.clone()),
+            OrderStatus::Expired if status_changed => OrderEventKind::Expired(order.clone()),
+            _ if !executions.is_empty() =>
+                OrderEventKind::PartiallyExecuted { order: order.clone(), executions },
+            _ => OrderEventKind::Changed { previous: previous.clone(), order: order.clone() },
+        };
+        Some(kind)
+    }
+}
+
+impl<'c, 'd> OrderWatcher<'c, 'd> {
+    pub(crate) fn new(client: &'c ApiClient, deposits: Vec<&'d ComdirectDeposit>) -> Self {
+        Self {
+            client,
+            deposits,
+            interval: DEFAULT_INTERVAL,
+            diff: OrderBookDiff::default(),
+        }
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Requests the order books of all deposits once and returns the changes since the last poll.
+    /// The first poll only records the current order books.
+    pub fn poll(&mut self) -> Result<Vec<OrderEvent>, Error> {
+        let mut events = Vec::new();
+        for deposit in &self.deposits {
+            let orders = self.client
+                .get_orders(deposit)?
+                .into_iter()
+                .map(|order| order.into_raw())
+                .collect();
+            for event in self.diff.update(deposit.id(), orders) {
+                events.push(self.resolve_removed(deposit, event)?);
+            }
+        }
+        Ok(events)
+    }
+
+    /// requests orders, that dropped out of the order book, to find out what happened to them
+    fn resolve_removed(&self, deposit: &ComdirectDeposit, event: OrderEvent) -> Result<OrderEvent, Error> {
+        let previous = match event.kind {
+            OrderEventKind::Removed(ref previous) => previous,
+            _ => return Ok(event),
+        };
+
+        let current = match self.client.get_order(deposit, &previous.id) {
+            Ok(order) => order.into_raw(),
+            Err(Error::NotFound) => return Ok(event),
+            Err(e) => return Err(e),
+        };
+        let current = match current {
+            RawOrder::SingleOrder(order) => Some(order),
+            RawOrder::CombinationOrder(order) => vec![order.sub_orders.0, order.sub_orders.1]
+                .into_iter()
+                .find(|order| order.id == previous.id),
+        };
+
+        let kind = current.and_then(|current| OrderBookDiff::event_kind(previous, &current));
+        match kind {
+            Some(kind) => Ok(OrderEvent { deposit_id: event.deposit_id, kind }),
+            None => Ok(event),
+        }
+    }
+
+    /// Polls the order books in the configured interval and calls `on_event` for every event,
+    /// until `on_event` returns `false` or a request fails.
+    pub fn watch<F: FnMut(OrderEvent) -> bool>(&mut self, mut on_event: F) -> Result<(), Error> {
+        loop {
+            for event in self.poll()? {
+                if !on_event(event) {
+                    return Ok(());
+                }
+            }
+            sleep(self.interval);
+        }
+    }
+
+    /// Polls the order books in the configured interval and sends every event,
+    /// until the receiver is dropped or a request fails.
+    pub fn watch_into(&mut self, sender: &Sender<OrderEvent>) -> Result<(), Error> {
+        self.watch(|event| sender.send(event).is_ok())
+    }
+}
@@ -0,0 +1,112 @@
+//! Lifetime-free variants of the deposit bound types.
+//!
+//! [`Order`], [`Position`], [`Transaction`] and [`Quote`] borrow their [`ComdirectDeposit`],
+//! which makes it hard to store them in long living structs or to send them to other threads.
+//! The owned variants share the deposit through an [`Arc`] instead. A borrowed view can be
+//! created at any time with `to_borrowed`, to use the owned values with the borrowing api.
+//!
+//! The outlines additionally own their instrument and market place ids. They are defined next
+//! to their borrowing variants and re-exported here.
+
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::types::deposit::ComdirectDeposit;
+use crate::types::order::{Order, RawOrder};
+use crate::types::position::{Position, RawPosition};
+use crate::types::quote::{Quote, QuoteTicket, RawQuote};
+use crate::types::transaction::{RawTransaction, Transaction};
+
+pub use crate::types::order::order_outline::{OwnedCombinationOrderOutline, OwnedOrderOutline, OwnedSingleOrderOutline};
+pub use crate::types::quote::OwnedQuoteOutline;
+
+macro_rules! owned_type {
+    ($(#[$meta:meta])* pub struct $owned:ident($borrowed:ident, $raw:ident)) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, getset::Getters)]
+        #[getset(get = "pub")]
+        pub struct $owned {
+            deposit: Arc<ComdirectDeposit>,
+            raw: $raw,
+        }
+
+        impl $owned {
+            /// Creates the owned value, that shares the given deposit.
+            /// Fails, if the deposit is not the one, the borrowed value belongs to.
+            pub fn with_deposit(value: $borrowed, deposit: Arc<ComdirectDeposit>) -> Result<Self, Error> {
+                if value.deposit().id() != deposit.id() {
+                    return Err(Error::DifferentDeposit);
+                }
+                Ok(Self { deposit, raw: value.into_raw() })
+            }
+
+            /// creates a borrowed view, that can be used with the borrowing api
+            #[inline(always)]
+            pub fn to_borrowed(&self) -> $borrowed<'_> {
+                $borrowed::from_raw(self.raw.clone(), &self.deposit)
+            }
+
+            #[inline(always)]
+            pub fn into_raw(self) -> $raw {
+                self.raw
+            }
+
+            #[allow(unused)]
+            #[inline(always)]
+            pub(crate) fn from_raw(raw: $raw, deposit: Arc<ComdirectDeposit>) -> Self {
+                Self { deposit, raw }
+            }
+
+            #[allow(unused)]
+            #[inline(always)]
+            pub(crate) fn set_raw(&mut self, raw: $raw) {
+                self.raw = raw;
+            }
+        }
+
+        /// clones the deposit into a new [`Arc`]; use `with_deposit` to share an existing one
+        impl From<$borrowed<'_>> for $owned {
+            fn from(value: $borrowed) -> Self {
+                let deposit = Arc::new((*value.deposit()).clone());
+                Self { deposit, raw: value.into_raw() }
+            }
+        }
+    };
+}
+
+owned_type!(pub struct OwnedOrder(Order, RawOrder));
+owned_type!(pub struct OwnedPosition(Position, RawPosition));
+owned_type!(pub struct OwnedTransaction(Transaction, RawTransaction));
+
+#[derive(Clone, Debug, getset::Getters)]
+#[getset(get = "pub")]
+pub struct OwnedQuote {
+    deposit: Arc<ComdirectDeposit>,
+    ticket: QuoteTicket,
+    raw: RawQuote,
+}
+
+impl OwnedQuote {
+    /// Creates the owned quote, that shares the given deposit.
+    /// Fails, if the deposit is not the one, the quote was requested for.
+    pub fn with_deposit(quote: Quote, deposit: Arc<ComdirectDeposit>) -> Result<Self, Error> {
+        let (quote_deposit, ticket, raw) = quote.into_parts();
+        if quote_deposit.id() != deposit.id() {
+            return Err(Error::DifferentDeposit);
+        }
+        Ok(Self { deposit, ticket, raw })
+    }
+
+    /// creates a borrowed view, that can be used with the borrowing api
+    #[inline(always)]
+    pub fn to_borrowed(&self) -> Quote<'_> {
+        Quote::from_raw(self.raw.clone(), self.ticket.clone(), &self.deposit)
+    }
+}
+
+impl From<Quote<'_>> for OwnedQuote {
+    fn from(quote: Quote) -> Self {
+        let (deposit, ticket, raw) = quote.into_parts();
+        Self { deposit: Arc::new(deposit.clone()), ticket, raw }
+    }
+}
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// the number of values requested per page, if not specified otherwise
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// The paging information, that comes with every list response of the comdirect API
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, getset::CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct Paging {
+    /// the index of the first value of the page
+    index: usize,
+    /// the total number of values matching the request (not the number of values of the page)
+    matches: usize,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, PartialEq, Eq, getset::CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct PagingParameters {
+    #[serde(rename = "paging-first")]
+    first: usize,
+    #[serde(rename = "paging-count")]
+    count: usize,
+}
+
+/// A lazy iterator over all values of a list endpoint.
+///
+/// Pages are only requested, once all values of the previous page were consumed.
+/// If a request fails, the error is yielded and the iteration ends.
+pub struct PageIter<'c, T> {
+    fetch_page: Box<dyn FnMut(PagingParameters) -> Result<(Paging, Vec<T>), Error> + 'c>,
+    /// values, that don't match, are skipped after the page was fetched, so the paging is unaffected
+    retain: Option<Box<dyn FnMut(&T) -> bool + 'c>>,
+    page_size: usize,
+    next_index: usize,
+    matches: Option<usize>,
+    page: std::vec::IntoIter<T>,
+    finished: bool,
+}
+
+impl PagingParameters {
+    pub const fn new(first: usize, count: usize) -> Self {
+        Self { first, count }
+    }
+}
+
+impl<'c, T> PageIter<'c, T> {
+    /// Creates an iterator, that requests the pages with `fetch_page`.
+    /// Every page has to be returned as it was received, so the next page starts after it.
+    pub fn new<F>(fetch_page: F) -> Self
+        where F: FnMut(PagingParameters) -> Result<(Paging, Vec<T>), Error> + 'c {
+        Self {
+            fetch_page: Box::new(fetch_page),
+            retain: None,
+            page_size: DEFAULT_PAGE_SIZE,
+            next_index: 0,
+            matches: None,
+            page: Vec::new().into_iter(),
+            finished: false,
+        }
+    }
+
+    /// sets the number of values requested per page
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Only yields the values, for which `retain` returns true. Unlike [`Iterator::filter`],
+    /// the iterator stays a `PageIter`.
+    pub fn retain<P>(mut self, retain: P) -> Self
+        where P: FnMut(&T) -> bool + 'c {
+        self.retain = Some(Box::new(retain));
+        self
+    }
+
+    /// the total number of values, as reported by the last requested page (ignoring `retain`)
+    pub fn matches(&self) -> Option<usize> {
+        self.matches
+    }
+
+    fn next_page(&mut self) -> Result<(), Error> {
+        let parameters = PagingParameters::new(self.next_index, self.page_size);
+        let (paging, values) = (self.fetch_page)(parameters)?;
+
+        self.matches = Some(paging.matches);
+        self.next_index += values.len();
+
+        if values.is_empty() || self.next_index >= paging.matches {
+            self.finished = true;
+        }
+
+        self.page = values.into_iter();
+        Ok(())
+    }
+}
+
+impl<T> Iterator for PageIter<'_, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.page.next() {
+                let retained = match &mut self.retain {
+                    Some(retain) => retain(&value),
+                    None => true
+                };
+                if retained {
+                    return Some(Ok(value));
+                }
+                continue;
+            }
+            if self.finished {
+                return None;
+            }
+            if let Err(e) = self.next_page() {
+                self.finished = true;
+                return Some(Err(e));
+            }
+        }
+    }
+}
@@ -1,14 +1,15 @@
 use chrono::Utc;
-use pecunia::price::{Price, TimeBoundedPrice};
-use pecunia::primitives::F64;
+use pecunia::prelude::*;
+use pecunia::price::TimeBoundedPrice;
 use reqwest::blocking::Response;
 use serde::de::DeserializeSeed;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Deserializer;
-use wall_street::derivative::WKN;
+use wall_street::derivative::{Derivative, WKN};
 
 use crate::error::Error;
 use crate::types::deposit::ComdirectDeposit;
+use crate::types::instrument::{Instrument, InstrumentId};
 
 new_type_ids!(
     pub struct PositionId
@@ -22,24 +23,87 @@ pub struct Position<'d> {
     raw: RawPosition,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, getset::Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
 #[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawPosition {
     #[serde(rename = "positionId")]
     id: PositionId,
     wkn: WKN,
-    /// for explanation, why this is a price, see [quantity](crate::order::RawSingleOrder::quantity)
+    #[serde(default)]
+    custody_type: Option<String>,
+    /// the number of units held
     #[serde(with = "crate::serde::amount_value::quantity")]
     quantity: F64,
+    /// the quantity, that can actually be traded, i.e. excluding blocked employee shares
+    #[serde(with = "crate::serde::amount_value::quantity")]
+    available_quantity: F64,
     #[serde(with = "crate::serde::time_bounded_price")]
     current_price: TimeBoundedPrice<Utc>,
+    #[serde(default)]
+    current_price_determinable: bool,
+    #[serde(default)]
+    #[serde(rename = "prevDayPrice")]
+    #[serde(with = "crate::serde::time_bounded_price::option")]
+    previous_day_price: Option<TimeBoundedPrice<Utc>>,
+    #[serde(default)]
     #[serde(with = "crate::serde::amount_value::price::option")]
     purchase_price: Option<Price>,
     #[serde(with = "crate::serde::amount_value::price")]
     current_value: Price,
+    #[serde(default)]
     #[serde(with = "crate::serde::amount_value::price::option")]
     purchase_value: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPurchaseAbs")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    profit_loss_purchase: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPurchaseRel")]
+    profit_loss_purchase_relative: Option<Percent>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPrevDayAbs")]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    profit_loss_previous_day: Option<Price>,
+    #[serde(default)]
+    #[serde(rename = "profitLossPrevDayRel")]
+    profit_loss_previous_day_relative: Option<Percent>,
+    #[serde(default)]
+    hedgeability: Option<Hedgeability>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::quantity::option")]
+    available_quantity_to_hedge: Option<F64>,
+    /// only present, if the positions were requested with instruments
+    #[serde(default)]
+    instrument: Option<Instrument>,
+}
+
+response_enum! {
+    pub enum Hedgeability {
+        Hedgeable = "HEDGEABLE",
+        NotHedgeable = "NOT_HEDGEABLE",
+        TemporarilyNotHedgeable = "TEMPORARILY_NOT_HEDGEABLE",
+    }
+}
+
+/// The difference between the positions passed to [`update_positions`](crate::interface::ApiClient::update_positions)
+/// and the positions currently held in the deposit
+#[derive(Clone, Debug, Default, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
+pub struct PositionChanges<'d> {
+    pub(crate) added: Vec<Position<'d>>,
+    pub(crate) closed: Vec<PositionId>,
+}
+
+impl<'d> PositionChanges<'d> {
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.closed.is_empty()
+    }
+
+    pub fn into_parts(self) -> (Vec<Position<'d>>, Vec<PositionId>) {
+        (self.added, self.closed)
+    }
 }
 
 macro_rules! set_field {
@@ -58,6 +122,31 @@ impl<'d> Position<'d> {
         }
     }
 
+    #[inline(always)]
+    pub fn into_raw(self) -> RawPosition {
+        self.raw
+    }
+
+    /// only available, if the position was requested with its instrument
+    #[inline(always)]
+    pub fn instrument_id(&self) -> Option<&InstrumentId> {
+        self.raw.instrument.as_ref().map(Instrument::id)
+    }
+
+    /// Whether the instrument id refers to the instrument of the position. The instrument id of
+    /// an order is the WKN or ISIN, the order was placed with, so these are compared as well.
+    pub fn holds_instrument(&self, instrument_id: &InstrumentId) -> bool {
+        let id = instrument_id.as_str();
+        let wkn: String = Derivative::WKN(self.raw.wkn.clone()).into();
+
+        self.instrument_id() == Some(instrument_id)
+            || wkn == id
+            || self.raw.instrument.as_ref().map_or(false, |instrument| {
+                let isin: String = Derivative::ISIN(instrument.isin().clone()).into();
+                isin == id
+            })
+    }
+
     pub(crate) fn update_from_response(&mut self, response: Response) -> Result<(), Error> {
         let body = response.bytes()?;
         let mut deserializer = Deserializer::from_slice(&body);
@@ -65,6 +154,17 @@ impl<'d> Position<'d> {
     }
 
     set_field!(set_quantity(quantity: F64));
+    set_field!(set_available_quantity(available_quantity: F64));
     set_field!(set_current_price(current_price: TimeBoundedPrice<Utc>));
+    set_field!(set_current_price_determinable(current_price_determinable: bool));
+    set_field!(set_previous_day_price(previous_day_price: Option<TimeBoundedPrice<Utc>>));
+    set_field!(set_purchase_price(purchase_price: Option<Price>));
     set_field!(set_current_value(current_value: Price));
+    set_field!(set_purchase_value(purchase_value: Option<Price>));
+    set_field!(set_profit_loss_purchase(profit_loss_purchase: Option<Price>));
+    set_field!(set_profit_loss_purchase_relative(profit_loss_purchase_relative: Option<Percent>));
+    set_field!(set_profit_loss_previous_day(profit_loss_previous_day: Option<Price>));
+    set_field!(set_profit_loss_previous_day_relative(profit_loss_previous_day_relative: Option<Percent>));
+    set_field!(set_hedgeability(hedgeability: Option<Hedgeability>));
+    set_field!(set_available_quantity_to_hedge(available_quantity_to_hedge: Option<F64>));
 }
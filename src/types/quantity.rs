@@ -0,0 +1,80 @@
+use pecunia::prelude::*;
+use pecunia::units::currency::Currency;
+use serde::{Deserialize, Serialize};
+
+/// The quantity of an order, execution or transaction.
+///
+/// Most of the time the quantity is a number of units (the unit is `XXX`). Savings plan orders
+/// buy a variable number of units for a fixed amount, so their quantities are monetary amounts.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum Quantity {
+    Units(#[serde(with = "crate::serde::amount_value::quantity")] F64),
+    Amount(#[serde(with = "crate::serde::amount_value::price")] Price),
+}
+
+impl Quantity {
+    #[inline(always)]
+    pub fn is_units(&self) -> bool {
+        matches!(self, Quantity::Units(_))
+    }
+
+    #[inline(always)]
+    pub fn is_amount(&self) -> bool {
+        matches!(self, Quantity::Amount(_))
+    }
+
+    #[inline(always)]
+    pub fn units(&self) -> Option<F64> {
+        match self {
+            Quantity::Units(units) => Some(*units),
+            Quantity::Amount(_) => None
+        }
+    }
+
+    #[inline(always)]
+    pub fn amount(&self) -> Option<&Price> {
+        match self {
+            Quantity::Units(_) => None,
+            Quantity::Amount(amount) => Some(amount)
+        }
+    }
+
+    /// the currency of an amount
+    #[inline(always)]
+    pub fn currency(&self) -> Option<Currency> {
+        self.amount().map(Price::currency)
+    }
+
+    /// the plain number, regardless of whether it is a number of units or an amount
+    pub fn value(&self) -> f64 {
+        match self {
+            Quantity::Units(units) => f64::from(*units),
+            Quantity::Amount(amount) => f64::from(amount.raw_price()),
+        }
+    }
+
+    /// The number of units, that were (or would be) traded for the `price`.
+    /// An amount is divided by the price, which has to be of the same currency.
+    pub fn units_at(&self, price: &Price) -> Option<f64> {
+        match self {
+            Quantity::Units(units) => Some(f64::from(*units)),
+            Quantity::Amount(amount) if amount.currency() == price.currency() => {
+                Some(f64::from(amount.raw_price()) / f64::from(price.raw_price()))
+            }
+            Quantity::Amount(_) => None
+        }
+    }
+}
+
+impl From<F64> for Quantity {
+    fn from(units: F64) -> Self {
+        Quantity::Units(units)
+    }
+}
+
+impl From<Price> for Quantity {
+    fn from(amount: Price) -> Self {
+        Quantity::Amount(amount)
+    }
+}
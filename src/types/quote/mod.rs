@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
 use getset::{Getters, Setters};
 use pecunia::price::Price;
@@ -5,6 +7,7 @@ use pecunia::primitives::F64;
 use serde::{Deserialize, Serialize};
 use wall_street::order::OrderDirection;
 
+use crate::error::Error;
 use crate::types::deposit::ComdirectDeposit;
 use crate::types::instrument::InstrumentId;
 use crate::types::market_place::MarketPlaceId;
@@ -22,7 +25,7 @@ pub struct Quote<'d> {
     raw: RawQuote,
 }
 
-#[derive(Clone, Debug, Deserialize, Getters)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters)]
 #[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawQuote {
@@ -68,6 +71,18 @@ pub struct QuoteOutline<'d, 'i, 'm> {
     quantity: F64,
 }
 
+/// Lifetime-free variant of [`QuoteOutline`], that shares the deposit through an [`Arc`]
+/// and owns the instrument and market place ids (see [`owned`](crate::types::owned)).
+#[derive(Clone, Debug, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct OwnedQuoteOutline {
+    deposit: Arc<ComdirectDeposit>,
+    instrument_id: InstrumentId,
+    market_place_id: MarketPlaceId,
+    direction: OrderDirection,
+    quantity: F64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Getters)]
 #[getset(get = "pub")]
 pub struct QuoteTicket {
@@ -80,10 +95,60 @@ impl<'d> Quote<'d> {
     pub(crate) fn from_raw(raw: RawQuote, ticket: QuoteTicket, deposit: &'d ComdirectDeposit) -> Self {
         Self { deposit, ticket, raw }
     }
+
+    #[inline(always)]
+    pub(crate) fn into_parts(self) -> (&'d ComdirectDeposit, QuoteTicket, RawQuote) {
+        (self.deposit, self.ticket, self.raw)
+    }
 }
 
 impl<'d, 'i, 'm> QuoteOutline<'d, 'i, 'm> {
+    pub fn new(
+        deposit: &'d ComdirectDeposit,
+        instrument_id: &'i InstrumentId,
+        market_place_id: &'m MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+    ) -> Self {
+        Self { deposit, instrument_id, market_place_id, direction, quantity }
+    }
+
     pub fn builder() -> QuoteOutlineBuilder<'d, 'i, 'm> {
         QuoteOutlineBuilder::default()
     }
 }
+
+impl OwnedQuoteOutline {
+    pub fn new(
+        deposit: Arc<ComdirectDeposit>,
+        instrument_id: InstrumentId,
+        market_place_id: MarketPlaceId,
+        direction: OrderDirection,
+        quantity: F64,
+    ) -> Self {
+        Self { deposit, instrument_id, market_place_id, direction, quantity }
+    }
+
+    /// Creates the owned outline, that shares the given deposit.
+    /// Fails, if the deposit is not the one of the outline.
+    pub fn with_deposit(outline: QuoteOutline, deposit: Arc<ComdirectDeposit>) -> Result<Self, Error> {
+        if outline.deposit.id() != deposit.id() {
+            return Err(Error::DifferentDeposit);
+        }
+        Ok(Self::new(deposit, outline.instrument_id.clone(), outline.market_place_id.clone(), outline.direction, outline.quantity))
+    }
+
+    /// creates a borrowed view, that can be used with the borrowing api
+    #[inline(always)]
+    pub fn to_borrowed(&self) -> QuoteOutline<'_, '_, '_> {
+        QuoteOutline::new(&self.deposit, &self.instrument_id, &self.market_place_id, self.direction, self.quantity)
+    }
+}
+
+/// clones the deposit into a new [`Arc`]; use `with_deposit` to share an existing one
+impl From<QuoteOutline<'_, '_, '_>> for OwnedQuoteOutline {
+    fn from(outline: QuoteOutline) -> Self {
+        let deposit = Arc::new(outline.deposit.clone());
+        Self::new(deposit, outline.instrument_id.clone(), outline.market_place_id.clone(), outline.direction, outline.quantity)
+    }
+}
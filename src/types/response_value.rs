@@ -0,0 +1,44 @@
+/// A value of a response, that is either known to this crate or kept as it was received.
+///
+/// Used for the enums of [wall_street], that have no `Unknown` variant. Unlike a neutral
+/// fallback, an unknown value is not lost and is serialized again as it was received.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResponseValue<T> {
+    Known(T),
+    /// a value, that is unknown to this crate
+    Unknown(String),
+}
+
+impl<T> ResponseValue<T> {
+    #[inline(always)]
+    pub fn known(&self) -> Option<&T> {
+        match self {
+            ResponseValue::Known(value) => Some(value),
+            ResponseValue::Unknown(_) => None
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ResponseValue::Unknown(_))
+    }
+}
+
+impl<T: Copy> ResponseValue<T> {
+    #[inline(always)]
+    pub fn copied(&self) -> Option<T> {
+        self.known().copied()
+    }
+}
+
+impl<T> From<T> for ResponseValue<T> {
+    fn from(value: T) -> Self {
+        ResponseValue::Known(value)
+    }
+}
+
+impl<T: Default> Default for ResponseValue<T> {
+    fn default() -> Self {
+        ResponseValue::Known(T::default())
+    }
+}
@@ -0,0 +1,87 @@
+//! A stable JSON format for caching response types or forwarding them to other services.
+//!
+//! Every value is wrapped in an envelope, that carries the version of the format:
+//!
+//! ```json
+//! {
+//!     "schemaVersion": 1,
+//!     "value": { ... }
+//! }
+//! ```
+//!
+//! # Version 1
+//!
+//! The `value` has the same layout as the comdirect API response of the type, with these exceptions:
+//! - fields, that were not part of the response, are written as `null` or left out
+//! - the order validity is always written explicitly (`validityType` and, for GTD, `validity`),
+//!   unknown validity types are written without their date
+//! - instruments carry the `derivativeData` of warrants and certificates and the `fundData`
+//!   of funds and ETFs
+//! - quantities of savings plan orders, executions and transactions are monetary amounts,
+//!   so their `unit` is a currency instead of `XXX`
+//!
+//! Whenever this layout changes, [`SCHEMA_VERSION`] is increased. Values of an unknown
+//! version are rejected with [`Error::UnsupportedSchemaVersion`].
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::types::cost_indication::RawCostIndication;
+use crate::types::deposit::{ComdirectDeposit, DepotSummary};
+use crate::types::document::Document;
+use crate::types::instrument::Instrument;
+use crate::types::market_place::MarketPlace;
+use crate::types::order::RawOrder;
+use crate::types::position::RawPosition;
+use crate::types::quote::RawQuote;
+use crate::types::transaction::RawTransaction;
+
+/// the current version of the JSON format
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// marks all types, that can be stored in the versioned JSON format
+pub trait SchemaType: Serialize + DeserializeOwned {}
+
+macro_rules! schema_type {
+    ($($ty:ty),*) => {
+        $(impl SchemaType for $ty {})*
+    };
+}
+
+schema_type!(
+    ComdirectDeposit, DepotSummary, Document, Instrument, MarketPlace,
+    RawCostIndication, RawOrder, RawPosition, RawQuote, RawTransaction
+);
+
+impl<T: SchemaType> SchemaType for Vec<T> {}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope<'v, T> {
+    schema_version: u32,
+    value: &'v T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VersionedEnvelope {
+    schema_version: u32,
+    value: serde_json::Value,
+}
+
+pub fn to_json<T: SchemaType>(value: &T) -> Result<String, Error> {
+    Ok(serde_json::to_string(&Envelope { schema_version: SCHEMA_VERSION, value })?)
+}
+
+pub fn to_json_pretty<T: SchemaType>(value: &T) -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(&Envelope { schema_version: SCHEMA_VERSION, value })?)
+}
+
+pub fn from_json<T: SchemaType>(json: &str) -> Result<T, Error> {
+    let envelope = serde_json::from_str::<VersionedEnvelope>(json)?;
+    if envelope.schema_version != SCHEMA_VERSION {
+        return Err(Error::UnsupportedSchemaVersion);
+    }
+    Ok(serde_json::from_value(envelope.value)?)
+}
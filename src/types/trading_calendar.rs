@@ -0,0 +1,116 @@
+//! The trading days of the german exchanges, which are used to convert relative order validities
+//! (one week, one month, one year) into a `GTD` date, since comdirect only knows `GFD` and `GTD`.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+use wall_street::order::OrderValidity;
+
+use crate::error::Error;
+
+/// the current date in Europe/Berlin, the time zone of the german exchanges
+#[inline(always)]
+pub fn today_in_berlin() -> NaiveDate {
+    berlin_date(Utc::now())
+}
+
+/// The date in Europe/Berlin at the time.
+/// Central European Summer Time lasts from the last sunday of march until the last sunday of
+/// october, changing at 01:00 UTC.
+pub fn berlin_date(time: DateTime<Utc>) -> NaiveDate {
+    let year = time.year();
+    let summer_time_start = Utc.from_utc_datetime(&last_sunday(year, 3).and_hms(1, 0, 0));
+    let summer_time_end = Utc.from_utc_datetime(&last_sunday(year, 10).and_hms(1, 0, 0));
+
+    let offset = match time >= summer_time_start && time < summer_time_end {
+        true => Duration::hours(2),
+        false => Duration::hours(1),
+    };
+    (time + offset).naive_utc().date()
+}
+
+/// whether the german exchanges are open at this date
+pub fn is_trading_day(date: NaiveDate) -> bool {
+    if date.weekday() == Weekday::Sat || date.weekday() == Weekday::Sun {
+        return false;
+    }
+
+    let easter = easter_sunday(date.year());
+    let is_holiday = matches!((date.month(), date.day()), (1, 1) | (5, 1) | (12, 24) | (12, 25) | (12, 26) | (12, 31))
+        // good friday and easter monday
+        || date == easter - Duration::days(2)
+        || date == easter + Duration::days(1);
+
+    !is_holiday
+}
+
+/// the last trading day, that is not after the date
+pub fn last_trading_day(mut date: NaiveDate) -> NaiveDate {
+    while !is_trading_day(date) {
+        date = date.pred();
+    }
+    date
+}
+
+/// Converts the validity into one, that can be sent to comdirect.
+///
+/// One week, one month and one year validities are converted into a `TillDate` validity, that
+/// ends at the last trading day of the period. The documentation does not name a maximum
+/// validity, so a `GTD` date, that is too far in the future, is rejected by comdirect itself.
+/// An infinite validity is rejected with [`Error::UnsupportedValidity`].
+pub fn comdirect_validity(validity: OrderValidity, today: NaiveDate) -> Result<OrderValidity, Error> {
+    let end = match validity {
+        OrderValidity::OneDay | OrderValidity::TillDate(_) => return Ok(validity),
+        OrderValidity::Infinite => return Err(Error::UnsupportedValidity),
+        OrderValidity::OneWeek => today + Duration::weeks(1),
+        OrderValidity::OneMonth => add_months(today, 1),
+        OrderValidity::OneYear => add_months(today, 12),
+    };
+
+    Ok(OrderValidity::TillDate(last_trading_day(end)))
+}
+
+/// Resolves relative validities with the current date in Europe/Berlin (see [`comdirect_validity`]),
+/// so an outline is sent with the same `GTD` date, no matter when it is sent.
+/// An infinite validity is kept, so it can be rejected, once the outline is sent.
+pub(crate) fn resolve_validity(validity: OrderValidity) -> OrderValidity {
+    comdirect_validity(validity, today_in_berlin()).unwrap_or(validity)
+}
+
+fn last_sunday(year: i32, month: u32) -> NaiveDate {
+    let mut date = add_months(NaiveDate::from_ymd(year, month, 1), 1).pred();
+    while date.weekday() != Weekday::Sun {
+        date = date.pred();
+    }
+    date
+}
+
+/// adds the months and uses the last day of the month, if the day does not exist (i.e. 31st)
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    let months = date.month0() + months;
+    let year = date.year() + (months / 12) as i32;
+    let month = months % 12 + 1;
+
+    (1..=date.day())
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .expect("every month has a first day")
+}
+
+/// calculates the date of easter sunday with the anonymous gregorian algorithm
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd(year, month as u32, day as u32)
+}
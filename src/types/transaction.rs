@@ -4,32 +4,45 @@ use serde::{Deserialize, Serialize};
 use wall_street::derivative::{ISIN, WKN};
 
 use crate::types::deposit::ComdirectDeposit;
+use crate::types::instrument::{Instrument, InstrumentId};
 use crate::types::position::Position;
+use crate::types::quantity::Quantity;
 
 new_type_ids!(
     pub struct TransactionId
 );
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
 pub struct Transaction<'d> {
     deposit: &'d ComdirectDeposit,
     raw: RawTransaction,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, getset::Getters)]
+#[getset(get = "pub")]
 #[serde(rename_all = "camelCase")]
 pub struct RawTransaction {
-    #[serde(default)]
-    #[serde(rename = "transaction_id")]
-    #[serde(deserialize_with = "TransactionId::de_option")]
-    id: Option<TransactionId>,
+    #[serde(rename = "transactionId")]
+    id: TransactionId,
     #[serde(rename = "bookingStatus")]
     status: BookingStatus,
-    #[serde(rename = "bookingDate")]
+    /// not booked transactions don't have a booking date yet
+    #[serde(default)]
+    #[serde(with = "crate::serde::date::date_string::option")]
+    booking_date: Option<NaiveDate>,
     #[serde(with = "crate::serde::date::date_string")]
-    date: NaiveDate,
-    #[serde(with = "crate::serde::amount_value::price")]
-    quantity: Price,
+    business_date: NaiveDate,
+    /// a number of units, or the amount of a savings plan execution (see [`Quantity`])
+    quantity: Quantity,
+    #[serde(rename = "instrumentId")]
+    instrument_id: InstrumentId,
+    /// only present, if the transactions were requested with instruments
+    #[serde(default)]
+    instrument: Option<Instrument>,
+    #[serde(default)]
+    #[serde(with = "crate::serde::amount_value::price::option")]
+    execution_price: Option<Price>,
     #[serde(rename = "transactionValue")]
     #[serde(with = "crate::serde::amount_value::price")]
     value: Price,
@@ -38,43 +51,53 @@ pub struct RawTransaction {
     transaction_type: TransactionType,
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum BookingStatus {
-    Booked,
-    NotBooked,
-    Both,
+response_enum! {
+    pub enum BookingStatus {
+        Booked = "BOOKED",
+        NotBooked = "NOTBOOKED",
+        Both = "BOTH",
+    }
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "UPPERCASE")]
-pub enum TransactionDirection {
-    In,
-    Out,
+response_enum! {
+    pub enum TransactionDirection {
+        In = "IN",
+        Out = "OUT",
+    }
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TransactionType {
-    Buy,
-    Sell,
-    TransferIn,
-    TransferOut,
-    Other,
+response_enum! {
+    pub enum TransactionType {
+        Buy = "BUY",
+        Sell = "SELL",
+        TransferIn = "TRANSFER_IN",
+        TransferOut = "TRANSFER_OUT",
+        Other = "OTHER",
+    }
 }
 
 #[derive(Clone, Debug, Default, serde::Serialize, getset::Setters)]
 #[getset(set = "pub")]
+#[serde(rename_all = "camelCase")]
 pub struct TransactionFilterParameters<'a> {
+    #[serde(rename = "ISIN")]
     isin: Option<&'a ISIN>,
+    #[serde(rename = "WKN")]
     wkn: Option<&'a WKN>,
-    instrument_id: Option<&'a str>,
+    instrument_id: Option<&'a InstrumentId>,
+    /// comdirect only documents `max-bookingDate`, so the lower bound is applied to the
+    /// received transactions instead of being sent
+    #[serde(skip)]
+    min_booking_date: Option<NaiveDate>,
+    #[serde(rename = "max-bookingDate")]
     #[serde(with = "crate::serde::date::date_string::option")]
     max_booking_date: Option<NaiveDate>,
     transaction_direction: Option<TransactionDirection>,
     transaction_type: Option<TransactionType>,
     booking_status: Option<BookingStatus>,
+    #[serde(rename = "min-transactionValue")]
     min_transaction_value: Option<Price>,
+    #[serde(rename = "max-transactionValue")]
     max_transaction_value: Option<Price>,
 }
 
@@ -85,6 +108,11 @@ impl<'d> Transaction<'d> {
             raw,
         }
     }
+
+    #[inline(always)]
+    pub fn into_raw(self) -> RawTransaction {
+        self.raw
+    }
 }
 
 impl<'a, 'm> TransactionFilterParameters<'a> {
@@ -92,4 +120,19 @@ impl<'a, 'm> TransactionFilterParameters<'a> {
         self.wkn = Some(position.raw().wkn());
         self
     }
+
+    /// Only returns transactions, that were booked between `min` and `max` (both inclusive).
+    /// Transactions without a booking date (`NOTBOOKED`) are skipped.
+    pub fn set_booking_date_range(mut self, min: NaiveDate, max: NaiveDate) -> Self {
+        self.min_booking_date = Some(min);
+        self.max_booking_date = Some(max);
+        self
+    }
+
+    pub(crate) fn matches_min_booking_date(&self, transaction: &Transaction) -> bool {
+        match self.min_booking_date {
+            Some(min) => transaction.raw.booking_date.map_or(false, |booking_date| booking_date >= min),
+            None => true
+        }
+    }
 }
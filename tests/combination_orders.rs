@@ -0,0 +1,152 @@
+//! offline tests of one cancels other and next orders
+
+use chrono::NaiveDate;
+use pecunia::prelude::*;
+use pecunia::units::currency::Currency;
+use wall_street::derivative::Derivative;
+use wall_street::order::{OrderDirection, OrderStatus, OrderValidity};
+
+use comdirect_api::types::deposit::ComdirectDeposit;
+use comdirect_api::types::instrument::InstrumentId;
+use comdirect_api::types::market_place::MarketPlaceId;
+use comdirect_api::types::order::RawOrder;
+use comdirect_api::types::order::combination::Leg;
+use comdirect_api::types::order::order_outline::OrderOutline;
+use comdirect_api::types::order::typed_outline::{LimitOrder, NextOrder, OneCancelsOtherOrder, StopMarketOrder};
+use comdirect_api::types::order::validation::OrderViolation;
+
+const DEPOSIT: &str = r#"{
+    "depotId": "1234_depot_UUID_1234",
+    "depotDisplayId": "000000001"
+}"#;
+
+const NEXT_ORDER: &str = r#"{
+    "depotId": "D0000000000000000000000000000001",
+    "orderId": "O0000000000000000000000000000002",
+    "orderType": "NEXT_ORDER",
+    "subOrders": [
+        {
+            "orderId": "O0000000000000000000000000000003",
+            "instrumentId": "I0000000000000000000000000000001",
+            "orderType": "LIMIT",
+            "side": "BUY",
+            "orderStatus": "EXECUTED",
+            "limit": {"value": "10", "unit": "EUR"},
+            "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+            "quantity": {"value": "10", "unit": "XXX"},
+            "executions": []
+        },
+        {
+            "orderId": "O0000000000000000000000000000004",
+            "instrumentId": "I0000000000000000000000000000001",
+            "orderType": "STOP_MARKET",
+            "side": "SELL",
+            "orderStatus": "WAITING",
+            "triggerLimit": {"value": "5.5", "unit": "EUR"},
+            "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+            "quantity": {"value": "5", "unit": "XXX"},
+            "executions": []
+        }
+    ]
+}"#;
+
+fn deposit() -> ComdirectDeposit {
+    serde_json::from_str(DEPOSIT).unwrap()
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from(Derivative::wkn_from_str("863186").unwrap())
+}
+
+fn market_place_id() -> MarketPlaceId {
+    serde_json::from_str(r#""1234_venue_UUID_1234""#).unwrap()
+}
+
+fn price(value: f64) -> Price {
+    Price::new(value, Currency::EUR)
+}
+
+fn validity() -> OrderValidity {
+    OrderValidity::TillDate(NaiveDate::from_ymd(2019, 12, 1))
+}
+
+/// the one cancels other example of the official documentation (11.2.6)
+#[test]
+fn one_cancels_other_order() {
+    let (deposit, instrument_id, market_place_id) = (deposit(), instrument_id(), market_place_id());
+
+    let stop = StopMarketOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Sell, F64::new(1.0), price(15.5))
+        .validity(validity());
+    let limit = LimitOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Sell, F64::new(1.0), price(50.0))
+        .validity(validity());
+    let outline: OrderOutline = OneCancelsOtherOrder::new(stop, limit).unwrap().into();
+
+    let json = serde_json::to_value(&outline).unwrap();
+    assert_eq!(json["depotId"], "1234_depot_UUID_1234");
+    assert_eq!(json["orderType"], "ONE_CANCELS_OTHER");
+    assert_eq!(json["subOrders"][0]["orderType"], "STOP_MARKET");
+    assert_eq!(json["subOrders"][0]["depotId"], "1234_depot_UUID_1234");
+    assert_eq!(json["subOrders"][0]["venueId"], "1234_venue_UUID_1234");
+    assert_eq!(json["subOrders"][0]["validityType"], "GTD");
+    assert_eq!(json["subOrders"][0]["validity"], "2019-12-01");
+    assert!(json["subOrders"][0].get("limit").is_none());
+    assert_eq!(json["subOrders"][1]["orderType"], "LIMIT");
+    assert!(json["subOrders"][1].get("triggerLimit").is_none());
+}
+
+/// the next order example of the official documentation (11.2.7)
+#[test]
+fn next_order() {
+    let (deposit, instrument_id, market_place_id) = (deposit(), instrument_id(), market_place_id());
+
+    let buy = LimitOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Buy, F64::new(10.0), price(10.0))
+        .validity(validity());
+    let stop_loss = StopMarketOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Sell, F64::new(5.0), price(5.5))
+        .validity(OrderValidity::OneDay);
+    let outline: OrderOutline = NextOrder::new(buy, stop_loss).unwrap().into();
+
+    let json = serde_json::to_value(&outline).unwrap();
+    assert_eq!(json["orderType"], "NEXT_ORDER");
+    assert_eq!(json["subOrders"][0]["side"], "BUY");
+    assert_eq!(json["subOrders"][1]["side"], "SELL");
+    assert_eq!(json["subOrders"][1]["validityType"], "GFD");
+}
+
+#[test]
+fn invalid_legs() {
+    let (deposit, instrument_id, market_place_id) = (deposit(), instrument_id(), market_place_id());
+    let other_instrument_id = InstrumentId::from(Derivative::wkn_from_str("856958").unwrap());
+
+    let stop = StopMarketOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Sell, F64::new(1.0), price(15.5));
+    let limit = LimitOrder::new(&deposit, &other_instrument_id, &market_place_id, OrderDirection::Buy, F64::new(2.0), price(50.0));
+    assert_eq!(
+        OneCancelsOtherOrder::new(stop, limit.clone()).unwrap_err(),
+        vec![
+            OrderViolation::DifferentInstruments,
+            OrderViolation::DifferentDirections,
+            OrderViolation::DifferentQuantities,
+        ]
+    );
+
+    let buy = LimitOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Buy, F64::new(1.0), price(10.0));
+    assert_eq!(NextOrder::new(buy.clone(), limit.clone()).unwrap_err(), vec![OrderViolation::DifferentInstruments]);
+
+    // the legs of a next order are not restricted any further, i.e. both may buy
+    let other_buy = LimitOrder::new(&deposit, &other_instrument_id, &market_place_id, OrderDirection::Buy, F64::new(1.0), price(10.0));
+    assert!(NextOrder::new(other_buy, limit).is_ok());
+}
+
+#[test]
+fn combined_status() {
+    let order = match serde_json::from_str::<RawOrder>(NEXT_ORDER).unwrap() {
+        RawOrder::CombinationOrder(order) => order,
+        RawOrder::SingleOrder(_) => panic!("expected a combination order"),
+    };
+
+    let status = order.status();
+    assert_eq!(status.leg(Leg::First), OrderStatus::Executed);
+    assert_eq!(status.leg(Leg::Second), OrderStatus::Pending);
+    assert_eq!(status.overall(), OrderStatus::Open);
+    assert_eq!(status.executed_leg(), Some(Leg::First));
+    assert!(status.is_active());
+}
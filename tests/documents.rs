@@ -0,0 +1,37 @@
+//! offline tests of the document category heuristic
+
+use comdirect_api::types::document::{Document, DocumentCategory};
+
+fn document(name: &str, advertisement: bool) -> Document {
+    serde_json::from_value(serde_json::json!({
+        "documentId": "D0000000000000000000000000000001",
+        "name": name,
+        "dateCreation": "2020-09-30",
+        "mimeType": "application/pdf",
+        "advertisement": advertisement,
+        "documentMetaData": {}
+    })).unwrap()
+}
+
+#[test]
+fn category_by_name() {
+    let categories = [
+        ("Wertpapierabrechnung Kauf AMD", DocumentCategory::TradeConfirmation),
+        ("Dividendengutschrift AMD", DocumentCategory::Dividend),
+        ("Ertragsgutschrift iShares Core MSCI World", DocumentCategory::Dividend),
+        ("Zinsgutschrift Tagesgeld", DocumentCategory::Interest),
+        ("Zinsertrag Anleihe", DocumentCategory::Interest),
+        ("Steuermitteilung", DocumentCategory::Tax),
+        ("Finanzreport Nr. 9", DocumentCategory::Statement),
+        ("Information zu Ihrem Depot", DocumentCategory::Other),
+    ];
+
+    for (name, category) in categories.iter() {
+        assert_eq!(document(name, false).category(), *category, "{}", name);
+    }
+}
+
+#[test]
+fn advertisement_ignores_the_name() {
+    assert_eq!(document("Zinsgutschrift Tagesgeld", true).category(), DocumentCategory::Advertisement);
+}
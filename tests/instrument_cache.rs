@@ -0,0 +1,109 @@
+//! offline tests of the instrument cache
+
+use std::convert::TryFrom;
+
+use chrono::Duration;
+use wall_street::derivative::{Derivative, ISIN, SYMBOL, WKN};
+
+use comdirect_api::types::instrument::Instrument;
+use comdirect_api::types::instrument::cache::InstrumentCache;
+
+const INSTRUMENT: &str = r#"{
+    "instrumentId": "I0000000000000000000000000000001",
+    "wkn": "863186",
+    "isin": "US0079031078",
+    "mnemonic": "AMD",
+    "name": "ADVANCED MICRO DEVICES INC. DL-,01",
+    "staticData": {
+        "currency": "USD",
+        "instrumentType": "SHARE"
+    }
+}"#;
+
+fn instrument() -> Instrument {
+    serde_json::from_str(INSTRUMENT).unwrap()
+}
+
+#[test]
+fn lookup_by_every_key() {
+    let mut cache = InstrumentCache::default();
+    cache.insert(instrument());
+
+    let wkn = Derivative::WKN(WKN::try_from("863186").unwrap());
+    let isin = Derivative::ISIN(ISIN::try_from("US0079031078").unwrap());
+    let symbol = Derivative::SYMBOL(SYMBOL::try_from("AMD").unwrap());
+
+    assert_eq!(cache.get(&wkn), Some(&instrument()));
+    assert_eq!(cache.get(&isin), Some(&instrument()));
+    assert_eq!(cache.get(&symbol), Some(&instrument()));
+    assert_eq!(cache.get_by_id(instrument().id()), Some(&instrument()));
+
+    cache.remove(instrument().id());
+    assert_eq!(cache.get(&wkn), None);
+}
+
+#[test]
+fn keys_of_different_kinds_do_not_collide() {
+    // the symbol of the first instrument is the WKN of the second one
+    let first: Instrument = serde_json::from_str(&INSTRUMENT.replace(r#""AMD""#, r#""A1EWWW""#)).unwrap();
+    let second: Instrument = serde_json::from_str(r#"{
+        "instrumentId": "I0000000000000000000000000000002",
+        "wkn": "A1EWWW",
+        "isin": "DE000A1EWWW0",
+        "mnemonic": "ADS",
+        "name": "ADIDAS AG NA O.N.",
+        "staticData": {
+            "currency": "EUR",
+            "instrumentType": "SHARE"
+        }
+    }"#).unwrap();
+
+    let mut cache = InstrumentCache::default();
+    cache.insert(first.clone());
+    cache.insert(second.clone());
+
+    assert_eq!(cache.get(&Derivative::SYMBOL(SYMBOL::try_from("A1EWWW").unwrap())), Some(&first));
+    assert_eq!(cache.get(&Derivative::WKN(WKN::try_from("A1EWWW").unwrap())), Some(&second));
+}
+
+#[test]
+fn reinsertion_replaces_the_old_keys() {
+    let mut cache = InstrumentCache::default();
+    cache.insert(instrument());
+
+    let renamed: Instrument = serde_json::from_str(&INSTRUMENT
+        .replace(r#""863186""#, r#""A0B7FY""#)
+        .replace(r#""AMD""#, r#""AMD1""#)
+    ).unwrap();
+    cache.insert(renamed.clone());
+
+    assert_eq!(cache.get(&Derivative::WKN(WKN::try_from("863186").unwrap())), None);
+    assert_eq!(cache.get(&Derivative::SYMBOL(SYMBOL::try_from("AMD").unwrap())), None);
+    assert_eq!(cache.get(&Derivative::WKN(WKN::try_from("A0B7FY").unwrap())), Some(&renamed));
+    assert_eq!(cache.get(&Derivative::ISIN(ISIN::try_from("US0079031078").unwrap())), Some(&renamed));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn expired_instruments_are_missing() {
+    let mut cache = InstrumentCache::new(Duration::zero());
+    cache.insert(instrument());
+
+    assert_eq!(cache.get_by_id(instrument().id()), None);
+    cache.remove_expired();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn persistence() {
+    let path = std::env::temp_dir().join("comdirect_api_instrument_cache.json");
+
+    let mut cache = InstrumentCache::default();
+    cache.insert(instrument());
+    cache.save(&path).unwrap();
+
+    let loaded = InstrumentCache::load(&path, Duration::hours(1)).unwrap();
+    assert_eq!(loaded.get_by_id(instrument().id()), Some(&instrument()));
+
+    std::fs::remove_file(path).unwrap();
+}
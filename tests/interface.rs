@@ -174,6 +174,27 @@ fn get_deposit_positions() {
     println!("\n\npositions: {:#?}", positions);
 }
 
+#[test]
+fn get_depot_overview() {
+    let deposit = deposit();
+    let overview = SESSION.get_depot_overview(&deposit).unwrap();
+    println!("\n\ndepot summary: {:#?}", overview.summary());
+
+    let positions = SESSION.get_positions(&deposit).unwrap();
+    assert_eq!(overview.positions().len(), positions.len());
+}
+
+#[test]
+fn get_positions_with_instruments() {
+    let deposit = deposit();
+    let positions = SESSION.get_positions_with_instruments(&deposit).unwrap();
+    for position in positions.iter() {
+        let instrument = position.raw().instrument().as_ref().unwrap();
+        assert_eq!(instrument.wkn(), position.raw().wkn());
+    }
+    println!("\n\npositions: {:#?}", positions);
+}
+
 #[test]
 fn update_position() {
     position!(mut position);
@@ -184,6 +205,56 @@ fn update_position() {
     println!("position: {:#?}", position);
 }
 
+#[test]
+fn update_positions() {
+    let deposit = deposit();
+    let mut positions = SESSION.get_positions(&deposit).unwrap();
+    let removed = positions.pop();
+
+    sleep(Duration::from_secs(2));
+    let changes = SESSION.update_positions(&mut positions).unwrap();
+    println!("\n\npositions: {:#?}", positions);
+    println!("changes: {:#?}", changes);
+
+    assert!(changes.closed().is_empty());
+    assert_eq!(
+        changes.added().first().map(|p| p.raw().id()),
+        removed.as_ref().map(|p| p.raw().id()),
+    );
+}
+
+#[test]
+fn load_deposit() {
+    let deposit = deposit();
+    let mut managed_deposit = SESSION.load_deposit(&deposit).unwrap();
+    for order in managed_deposit.orders().iter() {
+        println!("\n\norder {} belongs to position {:#?}", order.id(), managed_deposit.position_of(order));
+    }
+
+    let (positions, orders) = managed_deposit.split_mut();
+    let position = positions.iter().next();
+    SESSION.refresh_deposit_orders(orders).unwrap();
+    println!("position: {:#?}", position);
+
+    let changes = SESSION.refresh_deposit(&mut managed_deposit).unwrap();
+    println!("changes: {:#?}", changes);
+}
+
+#[test]
+fn owned_positions() {
+    let deposit = Arc::new(deposit());
+    let mut positions = SESSION.get_positions_owned(&deposit).unwrap();
+
+    let handle = std::thread::spawn(move || {
+        for position in positions.iter_mut() {
+            SESSION.update_owned_position(position).unwrap();
+        }
+        positions
+    });
+    let positions = handle.join().unwrap();
+    println!("\n\nowned positions: {:#?}", positions);
+}
+
 #[test]
 fn get_deposit_transactions() {
     let deposit = deposit();
@@ -191,6 +262,30 @@ fn get_deposit_transactions() {
     println!("\n\ntransactions: {:#?}", transactions);
 }
 
+#[test]
+fn iter_deposit_transactions() {
+    let deposit = deposit();
+    let transactions = SESSION
+        .iter_deposit_transactions(&deposit)
+        .page_size(5)
+        .take(12)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    println!("\n\nfirst transactions: {:#?}", transactions);
+}
+
+#[test]
+fn get_deposit_transactions_with_instruments() {
+    let deposit = deposit();
+    let transactions = SESSION
+        .iter_deposit_transactions_with_instruments(&deposit, None)
+        .take(5)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(transactions.iter().all(|t| t.raw().instrument().is_some()));
+    println!("\n\ntransactions: {:#?}", transactions);
+}
+
 #[test]
 fn get_deposit_filtered_transactions() {
     let deposit = deposit();
@@ -205,6 +300,46 @@ fn get_deposit_filtered_transactions() {
     println!("\n\ntransactions: {:#?}", transactions);
 }
 
+#[test]
+fn get_deposit_transactions_in_date_range() {
+    let deposit = deposit();
+    let max = chrono::Local::today().naive_local();
+    let min = max - chrono::Duration::days(90);
+    let parameters = TransactionFilterParameters::default()
+        .set_booking_date_range(min, max);
+
+    let transactions = SESSION.get_deposit_transactions_filtered(
+        &deposit,
+        &parameters,
+    ).unwrap();
+    for transaction in transactions.iter() {
+        // NOTBOOKED transactions have no booking date
+        if let Some(booking_date) = *transaction.raw().booking_date() {
+            assert!(min <= booking_date && booking_date <= max);
+        }
+    }
+    println!("\n\ntransactions: {:#?}", transactions);
+}
+
+#[test]
+fn get_documents() {
+    let documents = SESSION.get_documents().unwrap();
+    println!("\n\ndocuments: {:#?}", documents);
+}
+
+#[test]
+#[ignore]
+fn archive_postbox() {
+    //! !downloading documents marks them as read!
+    let dir = std::env::temp_dir().join("comdirect_postbox");
+
+    let archived = SESSION.archive_postbox(&dir).unwrap();
+    println!("\n\narchived documents: {:#?}", archived);
+
+    let archived_again = SESSION.archive_postbox(&dir).unwrap();
+    assert!(archived_again.is_empty());
+}
+
 #[test]
 fn get_instrument() {
     // McDonald's
@@ -243,6 +378,20 @@ fn get_orders() {
     println!("\n\nall orders: {:#?}", orders);
 }
 
+#[test]
+fn iter_orders() {
+    let deposit = deposit();
+    let orders = SESSION.get_orders(&deposit).unwrap();
+
+    let iterated = SESSION
+        .iter_orders(&deposit)
+        .page_size(2)
+        .collect::<Result<Vec<Order>, _>>()
+        .unwrap();
+
+    assert_eq!(orders, iterated);
+}
+
 #[test]
 fn get_orders_filtered() {
     let deposit = deposit();
@@ -292,6 +441,30 @@ fn place_order() {
     let _: Order = SESSION.get_order(&deposit(), order.id()).unwrap();
 }
 
+#[test]
+#[ignore]
+fn prepare_order() {
+    use comdirect_api::TanChallengeType;
+    order_outline!(order_outline);
+
+    let aborted = SESSION.prepare_order(&order_outline).unwrap();
+    assert_eq!(aborted.tan_challenge().typ(), &TanChallengeType::Free);
+    aborted.abort();
+
+    let mut prepared = SESSION.prepare_order(&order_outline).unwrap();
+    let cost_indication = prepared.request_cost_indication().unwrap();
+    println!("cost indication: {:#?}", cost_indication);
+    let mut order = prepared.commit().unwrap();
+
+    let order_change = OrderChange::from_order0(&mut order)
+        .limit(Price::new(5.0, Currency::EUR));
+    let order_change = SESSION.prepare_order_change(order_change).unwrap().abort();
+    SESSION.prepare_order_change(order_change).unwrap().commit().unwrap();
+
+    let order = SESSION.prepare_order_deletion(order).unwrap().abort();
+    SESSION.prepare_order_deletion(order).unwrap().commit().unwrap();
+}
+
 #[test]
 #[ignore]
 fn pre_validate_order_change() {
@@ -434,3 +607,45 @@ fn place_quote_order() {
     let order: Order = SESSION.place_quote_order(quote_order_outline).unwrap();
     println!("order: {:#?}", order);
 }
+
+#[test]
+fn resolve_instruments() {
+    let derivatives = [
+        Derivative::WKN(WKN::try_from("856958").unwrap()),
+        Derivative::ISIN(ISIN::try_from("US0079031078").unwrap()),
+        Derivative::ISIN(ISIN::try_from("US5801351017").unwrap()),
+    ];
+
+    let instruments = SESSION.resolve_instruments(&derivatives).unwrap();
+    assert_eq!(instruments.len(), 3);
+    assert_eq!(instruments[0], instruments[2]);
+    assert!(SESSION.with_instrument_cache(|cache| cache.get(&derivatives[1]).is_some()));
+}
+
+#[test]
+fn watch_orders() {
+    let deposit = deposit();
+    let mut watcher = SESSION
+        .watch_orders(vec![&deposit])
+        .interval(Duration::from_secs(1));
+
+    // the first poll only records the order book
+    assert!(watcher.poll().unwrap().is_empty());
+    watcher.poll().unwrap();
+}
+
+#[test]
+#[ignore]
+fn wait_for_order() {
+    order_outline!(order_outline);
+    // the limit is far below the market price, so the order will not be executed in time
+    let order = SESSION.place_order(&order_outline).unwrap();
+
+    let fill = SESSION
+        .wait_for_order(&order, Duration::from_secs(3), Duration::from_secs(1), true)
+        .unwrap();
+
+    assert!(fill.timed_out());
+    assert!(fill.canceled_remainder());
+    assert_eq!(fill.executed_legs().count(), 0);
+}
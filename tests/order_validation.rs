@@ -0,0 +1,237 @@
+//! offline tests of the local order validation
+
+use pecunia::prelude::*;
+use pecunia::units::currency::Currency;
+use wall_street::derivative::Derivative;
+use wall_street::order::{AuctionType, OrderDirection, OrderType, OrderTypeExtension, OrderValidity};
+
+use comdirect_api::types::deposit::ComdirectDeposit;
+use comdirect_api::types::instrument::InstrumentId;
+use comdirect_api::types::market_place::MarketPlace;
+use comdirect_api::types::order::combination::Leg;
+use comdirect_api::types::order::order_outline::{OrderOutline, RawCombinationOrderOutline, RawSingleOrderOutline};
+use comdirect_api::types::order::typed_outline::{LimitOrder, MarketOrder, StopLimitOrder, StopMarketOrder, TrailingDistance, TrailingStopLimitOrder, TrailingStopOrder};
+use comdirect_api::types::order::validation::OrderViolation;
+
+const DEPOSIT: &str = r#"{
+    "depotId": "D0000000000000000000000000000001",
+    "depotDisplayId": "000000001"
+}"#;
+
+const MARKET_PLACE: &str = r#"{
+    "venueId": "V0000000000000000000000000000001",
+    "name": "Xetra",
+    "sides": ["BUY", "SELL"],
+    "validityTypes": ["GFD"],
+    "orderTypes": {
+        "MARKET": {
+            "limitExtensions": [],
+            "tradingRestrictions": ["OAO", "AO", "CAO"]
+        },
+        "LIMIT": {
+            "limitExtensions": ["IOC", "FOK"],
+            "tradingRestrictions": []
+        },
+        "STOP_MARKET": {
+            "limitExtensions": [],
+            "tradingRestrictions": []
+        },
+        "STOP_LIMIT": {
+            "limitExtensions": [],
+            "tradingRestrictions": []
+        },
+        "TRAILING_STOP_MARKET": {
+            "limitExtensions": [],
+            "tradingRestrictions": []
+        },
+        "ONE_CANCELS_OTHER": {
+            "limitExtensions": [],
+            "tradingRestrictions": []
+        }
+    }
+}"#;
+
+const TRAILING_MARKET_PLACE: &str = r#"{
+    "venueId": "V0000000000000000000000000000002",
+    "name": "Tradegate",
+    "sides": ["BUY", "SELL"],
+    "validityTypes": ["GFD", "GTD"],
+    "orderTypes": {
+        "TRAILING_STOP_MARKET": {
+            "limitExtensions": [],
+            "tradingRestrictions": []
+        },
+        "TRAILING_STOP_LIMIT": {
+            "limitExtensions": [],
+            "tradingRestrictions": []
+        }
+    }
+}"#;
+
+fn deposit() -> ComdirectDeposit {
+    serde_json::from_str(DEPOSIT).unwrap()
+}
+
+fn market_place() -> MarketPlace {
+    serde_json::from_str(MARKET_PLACE).unwrap()
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from(Derivative::isin_from_str("US0079031078").unwrap())
+}
+
+fn limit_order<'d, 'i, 'm>(deposit: &'d ComdirectDeposit, market_place: &'m MarketPlace, instrument_id: &'i InstrumentId)
+    -> RawSingleOrderOutline<'d, 'i, 'm> {
+    RawSingleOrderOutline::builder()
+        .deposit(deposit)
+        .market_place_id(market_place.id())
+        .instrument_id(instrument_id)
+        .order_type(OrderType::Limit)
+        .direction(OrderDirection::Sell)
+        .limit(Price::new(90.0, Currency::EUR))
+        .quantity(F64::new(1.0))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn valid_limit_order() {
+    let (deposit, market_place, instrument_id) = (deposit(), market_place(), instrument_id());
+    let order = OrderOutline::SingleOrder(limit_order(&deposit, &market_place, &instrument_id));
+    assert_eq!(order.validate(&market_place), Ok(()));
+}
+
+#[test]
+fn every_violation_is_returned() {
+    let (deposit, market_place, instrument_id) = (deposit(), market_place(), instrument_id());
+    let mut order = limit_order(&deposit, &market_place, &instrument_id);
+    order.set_limit(None);
+    order.set_trigger_limit(Some(Price::new(80.0, Currency::EUR)));
+    order.set_order_type_extension(OrderTypeExtension::AllOrNone);
+    order.set_auction(AuctionType::ClosingAuctionOnly);
+    order.set_validity(Some(OrderValidity::TillDate(chrono::NaiveDate::from_ymd(2020, 9, 30))));
+
+    let violations = OrderOutline::SingleOrder(order).violations(&market_place);
+    assert_eq!(violations, vec![
+        OrderViolation::ValidityNotSupported(OrderValidity::TillDate(chrono::NaiveDate::from_ymd(2020, 9, 30))),
+        OrderViolation::OrderTypeExtensionNotSupported(OrderTypeExtension::AllOrNone),
+        OrderViolation::AuctionTypeNotSupported(AuctionType::ClosingAuctionOnly),
+        OrderViolation::MissingLimit,
+        OrderViolation::UnexpectedTriggerLimit,
+    ]);
+}
+
+#[test]
+fn unsupported_order_type() {
+    let (deposit, market_place, instrument_id) = (deposit(), market_place(), instrument_id());
+    let mut order = limit_order(&deposit, &market_place, &instrument_id);
+    order.set_order_type(OrderType::TrailingStopLimit);
+
+    let violations = OrderOutline::SingleOrder(order).violations(&market_place);
+    assert_eq!(violations, vec![
+        OrderViolation::OrderTypeNotSupported(OrderType::TrailingStopLimit),
+        OrderViolation::MissingTriggerLimit,
+        OrderViolation::MissingTrailingDistance,
+    ]);
+}
+
+/// the request bodies of 11.2.4 and 11.2.5 of the documentation
+#[test]
+fn documented_trailing_stop_orders() {
+    let deposit = deposit();
+    let market_place: MarketPlace = serde_json::from_str(TRAILING_MARKET_PLACE).unwrap();
+    let instrument_id = instrument_id();
+
+    let trailing_stop_market = RawSingleOrderOutline::builder()
+        .deposit(&deposit)
+        .market_place_id(market_place.id())
+        .instrument_id(&instrument_id)
+        .order_type(OrderType::TrailingStopMarket)
+        .direction(OrderDirection::Sell)
+        .validity(OrderValidity::OneDay)
+        .trigger_limit(Price::new(10.0, Currency::EUR))
+        .absolute_trailing_limit(Price::new(1.0, Currency::EUR))
+        .quantity(F64::new(1.0))
+        .build()
+        .unwrap();
+
+    let trailing_stop_limit = RawSingleOrderOutline::builder()
+        .deposit(&deposit)
+        .market_place_id(market_place.id())
+        .instrument_id(&instrument_id)
+        .order_type(OrderType::TrailingStopLimit)
+        .direction(OrderDirection::Sell)
+        .validity(OrderValidity::OneDay)
+        .limit(Price::new(9.0, Currency::EUR))
+        .trigger_limit(Price::new(10.0, Currency::EUR))
+        .relative_trailing_limit(Percent::new(5.5))
+        .quantity(F64::new(1.0))
+        .build()
+        .unwrap();
+
+    for order in vec![trailing_stop_market, trailing_stop_limit] {
+        let mut without_trigger_limit = order.clone();
+        without_trigger_limit.set_trigger_limit(None);
+
+        assert_eq!(OrderOutline::SingleOrder(order).validate(&market_place), Ok(()));
+        assert_eq!(
+            OrderOutline::SingleOrder(without_trigger_limit).violations(&market_place),
+            vec![OrderViolation::MissingTriggerLimit]
+        );
+    }
+}
+
+#[test]
+fn sub_order_violations() {
+    let (deposit, market_place, instrument_id) = (deposit(), market_place(), instrument_id());
+    let limit = limit_order(&deposit, &market_place, &instrument_id);
+    let mut stop = limit.clone();
+    stop.set_order_type(OrderType::StopMarket);
+
+    let order = RawCombinationOrderOutline::builder()
+        .deposit(&deposit)
+        .order_type(OrderType::OneCancelsTheOther)
+        .sub_orders((limit, stop))
+        .build()
+        .unwrap();
+
+    let violations = OrderOutline::CombinationOrder(order).violations(&market_place);
+    assert_eq!(violations, vec![
+        OrderViolation::SubOrder(Leg::Second, Box::new(OrderViolation::MissingTriggerLimit)),
+        OrderViolation::SubOrder(Leg::Second, Box::new(OrderViolation::UnexpectedLimit)),
+    ]);
+}
+
+#[test]
+fn typed_outlines_are_valid() {
+    let (deposit, market_place, instrument_id) = (deposit(), market_place(), instrument_id());
+    let (market_place_id, quantity) = (market_place.id(), F64::new(1.0));
+    let price = || Price::new(90.0, Currency::EUR);
+
+    let outlines: Vec<OrderOutline> = vec![
+        MarketOrder::new(&deposit, &instrument_id, market_place_id, OrderDirection::Buy, quantity)
+            .auction(AuctionType::ClosingAuctionOnly)
+            .into(),
+        LimitOrder::new(&deposit, &instrument_id, market_place_id, OrderDirection::Buy, quantity, price())
+            .order_type_extension(OrderTypeExtension::ImmediateOrCancel)
+            .into(),
+        StopMarketOrder::new(&deposit, &instrument_id, market_place_id, OrderDirection::Sell, quantity, price())
+            .into(),
+        StopLimitOrder::new(&deposit, &instrument_id, market_place_id, OrderDirection::Sell, quantity, price(), price())
+            .into(),
+        TrailingStopOrder::new(&deposit, &instrument_id, market_place_id, OrderDirection::Sell, quantity, price(), TrailingDistance::Absolute(price()))
+            .validity(OrderValidity::OneDay)
+            .into(),
+    ];
+
+    for outline in outlines {
+        assert_eq!(outline.validate(&market_place), Ok(()), "{:?}", outline);
+    }
+
+    let market_place: MarketPlace = serde_json::from_str(TRAILING_MARKET_PLACE).unwrap();
+    let outline: OrderOutline = TrailingStopLimitOrder::new(
+        &deposit, &instrument_id, market_place.id(), OrderDirection::Sell, quantity,
+        Price::new(10.0, Currency::EUR), Price::new(9.0, Currency::EUR), TrailingDistance::Relative(Percent::new(5.5)),
+    ).into();
+    assert_eq!(outline.validate(&market_place), Ok(()));
+}
@@ -0,0 +1,99 @@
+//! offline tests of the order book diff of the order watcher
+
+use comdirect_api::types::deposit::DepositId;
+use comdirect_api::types::order::RawOrder;
+use comdirect_api::types::order::watcher::{OrderBookDiff, OrderEventKind};
+
+const OPEN_ORDER: &str = r#"{
+    "orderId": "O0000000000000000000000000000001",
+    "instrumentId": "I0000000000000000000000000000001",
+    "orderType": "LIMIT",
+    "side": "BUY",
+    "orderStatus": "OPEN",
+    "limit": {"value": "80.5", "unit": "EUR"},
+    "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+    "quantity": {"value": "10", "unit": "XXX"},
+    "openQuantity": {"value": "10", "unit": "XXX"},
+    "executions": []
+}"#;
+
+const EXECUTION: &str = r#"{
+    "executionId": "E0000000000000000000000000000001",
+    "executionNumber": 1,
+    "executedQuantity": {"value": "4", "unit": "XXX"},
+    "executionPrice": {"value": "80.5", "unit": "EUR"},
+    "executionTimestamp": "2020-08-04T09:01:02,000000+02"
+}"#;
+
+fn deposit_id() -> DepositId {
+    serde_json::from_str(r#""D0000000000000000000000000000001""#).unwrap()
+}
+
+fn order(json: &str) -> RawOrder {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn first_order_book_causes_no_events() {
+    let mut diff = OrderBookDiff::default();
+    assert!(diff.update(&deposit_id(), vec![order(OPEN_ORDER)]).is_empty());
+    assert!(diff.update(&deposit_id(), vec![order(OPEN_ORDER)]).is_empty());
+}
+
+#[test]
+fn order_lifecycle() {
+    let mut diff = OrderBookDiff::default();
+    diff.update(&deposit_id(), vec![]);
+
+    let events = diff.update(&deposit_id(), vec![order(OPEN_ORDER)]);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0].kind(), OrderEventKind::Created(_)));
+
+    let changed = OPEN_ORDER.replace(r#""value": "80.5""#, r#""value": "81""#);
+    let events = diff.update(&deposit_id(), vec![order(&changed)]);
+    assert!(matches!(events[0].kind(), OrderEventKind::Changed { .. }));
+
+    let partially_executed = changed
+        .replace("\"OPEN\"", "\"PARTIALLY_EXECUTED\"")
+        .replace(r#""executions": []"#, &format!(r#""executions": [{}]"#, EXECUTION));
+    let events = diff.update(&deposit_id(), vec![order(&partially_executed)]);
+    match events[0].kind() {
+        OrderEventKind::PartiallyExecuted { executions, .. } => assert_eq!(executions.len(), 1),
+        kind => panic!("unexpected event {:?}", kind),
+    }
+
+    let executed = partially_executed.replace("\"PARTIALLY_EXECUTED\"", "\"EXECUTED\"");
+    let events = diff.update(&deposit_id(), vec![order(&executed)]);
+    match events[0].kind() {
+        OrderEventKind::Executed { executions, .. } => assert!(executions.is_empty()),
+        kind => panic!("unexpected event {:?}", kind),
+    }
+}
+
+#[test]
+fn canceled_order() {
+    let mut diff = OrderBookDiff::default();
+    diff.update(&deposit_id(), vec![order(OPEN_ORDER)]);
+
+    let canceled = OPEN_ORDER.replace("\"OPEN\"", "\"CANCELLED_USER\"");
+    let events = diff.update(&deposit_id(), vec![order(&canceled)]);
+    assert!(matches!(events[0].kind(), OrderEventKind::Canceled(_)));
+}
+
+#[test]
+fn removed_order() {
+    let mut diff = OrderBookDiff::default();
+    diff.update(&deposit_id(), vec![order(OPEN_ORDER)]);
+
+    let events = diff.update(&deposit_id(), vec![]);
+    assert_eq!(events.len(), 1);
+    match events[0].kind() {
+        OrderEventKind::Removed(order) => assert_eq!(order.id().as_str(), "O0000000000000000000000000000001"),
+        kind => panic!("unexpected event {:?}", kind),
+    }
+
+    // final orders, that drop out of the order book, were already reported
+    let canceled = OPEN_ORDER.replace("\"OPEN\"", "\"CANCELLED_USER\"");
+    diff.update(&deposit_id(), vec![order(&canceled)]);
+    assert!(diff.update(&deposit_id(), vec![]).is_empty());
+}
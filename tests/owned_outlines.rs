@@ -0,0 +1,61 @@
+//! offline tests of the owned order and quote outlines
+
+use std::sync::Arc;
+
+use pecunia::prelude::*;
+use pecunia::units::currency::Currency;
+use wall_street::derivative::Derivative;
+use wall_street::order::OrderDirection;
+
+use comdirect_api::error::Error;
+use comdirect_api::types::deposit::ComdirectDeposit;
+use comdirect_api::types::instrument::InstrumentId;
+use comdirect_api::types::market_place::MarketPlaceId;
+use comdirect_api::types::order::order_outline::OrderOutline;
+use comdirect_api::types::order::typed_outline::{LimitOrder, OneCancelsOtherOrder, StopMarketOrder};
+use comdirect_api::types::owned::{OwnedOrderOutline, OwnedQuoteOutline};
+use comdirect_api::types::quote::QuoteOutline;
+
+fn deposit_with_id(id: &str) -> ComdirectDeposit {
+    serde_json::from_str(&format!(r#"{{"depotId": "{}", "depotDisplayId": "000000001"}}"#, id)).unwrap()
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from(Derivative::wkn_from_str("863186").unwrap())
+}
+
+fn market_place_id() -> MarketPlaceId {
+    serde_json::from_str(r#""1234_venue_UUID_1234""#).unwrap()
+}
+
+#[test]
+fn order_outline_round_trip() {
+    let (deposit, instrument_id, market_place_id) = (deposit_with_id("1234_depot_UUID_1234"), instrument_id(), market_place_id());
+
+    let stop = StopMarketOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Sell, F64::new(1.0), Price::new(15.5, Currency::EUR));
+    let limit = LimitOrder::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Sell, F64::new(1.0), Price::new(50.0, Currency::EUR));
+    let outline: OrderOutline = OneCancelsOtherOrder::new(stop, limit).unwrap().into();
+
+    let shared = Arc::new(deposit.clone());
+    let owned = OwnedOrderOutline::with_deposit(outline.clone(), Arc::clone(&shared)).unwrap();
+    assert!(Arc::ptr_eq(owned.deposit(), &shared));
+    assert_eq!(owned.to_borrowed(), outline);
+
+    let other_deposit = Arc::new(deposit_with_id("5678_depot_UUID_5678"));
+    assert_eq!(OwnedOrderOutline::with_deposit(outline, other_deposit), Err(Error::DifferentDeposit));
+}
+
+#[test]
+fn quote_outline_round_trip() {
+    let (deposit, instrument_id, market_place_id) = (deposit_with_id("1234_depot_UUID_1234"), instrument_id(), market_place_id());
+    let outline = QuoteOutline::new(&deposit, &instrument_id, &market_place_id, OrderDirection::Buy, F64::new(3.0));
+
+    let owned = OwnedQuoteOutline::from(outline.clone());
+    assert_eq!(
+        serde_json::to_value(owned.to_borrowed()).unwrap(),
+        serde_json::to_value(&outline).unwrap(),
+    );
+
+    let other_deposit = Arc::new(deposit_with_id("5678_depot_UUID_5678"));
+    assert_eq!(OwnedQuoteOutline::with_deposit(outline, other_deposit), Err(Error::DifferentDeposit));
+}
@@ -0,0 +1,31 @@
+//! offline tests of the lazy paging iterator with a mocked list endpoint
+
+use std::cell::RefCell;
+
+use comdirect_api::types::paging::{PageIter, Paging, PagingParameters};
+
+const VALUES: [u32; 7] = [1, 3, 5, 2, 4, 6, 7];
+
+fn paging(index: usize) -> Paging {
+    serde_json::from_value(serde_json::json!({"index": index, "matches": VALUES.len()})).unwrap()
+}
+
+#[test]
+fn retain_keeps_the_paging() {
+    let requested = RefCell::new(Vec::new());
+    let pages = PageIter::new(|parameters: PagingParameters| {
+        requested.borrow_mut().push(parameters.first());
+        let end = (parameters.first() + parameters.count()).min(VALUES.len());
+        Ok((paging(parameters.first()), VALUES[parameters.first()..end].to_vec()))
+    });
+
+    // the second page contains no odd value at all
+    let odd: Vec<u32> = pages
+        .page_size(3)
+        .retain(|value| value % 2 == 1)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(odd, vec![1, 3, 5, 7]);
+    assert_eq!(*requested.borrow(), vec![0, 3, 6]);
+}
@@ -0,0 +1,36 @@
+//! offline tests of units and amounts as quantities
+
+use pecunia::prelude::*;
+use pecunia::units::currency::Currency;
+
+use comdirect_api::types::quantity::Quantity;
+
+#[test]
+fn deserialize() {
+    let units: Quantity = serde_json::from_str(r#"{"value": "10", "unit": "XXX"}"#).unwrap();
+    assert_eq!(units, Quantity::Units(F64::new(10.0)));
+    assert_eq!(units.units(), Some(F64::new(10.0)));
+    assert!(units.amount().is_none());
+
+    let amount: Quantity = serde_json::from_str(r#"{"value": "25", "unit": "EUR"}"#).unwrap();
+    assert_eq!(amount, Quantity::Amount(Price::new(25.0, Currency::EUR)));
+    assert_eq!(amount.currency(), Some(Currency::EUR));
+    assert!(amount.units().is_none());
+}
+
+#[test]
+fn round_trip() {
+    for quantity in vec![Quantity::Units(F64::new(3.0)), Quantity::Amount(Price::new(50.0, Currency::EUR))] {
+        let json = serde_json::to_string(&quantity).unwrap();
+        assert_eq!(serde_json::from_str::<Quantity>(&json).unwrap(), quantity);
+    }
+}
+
+#[test]
+fn units_at() {
+    let price = Price::new(12.5, Currency::EUR);
+
+    assert_eq!(Quantity::Units(F64::new(4.0)).units_at(&price), Some(4.0));
+    assert_eq!(Quantity::Amount(Price::new(50.0, Currency::EUR)).units_at(&price), Some(4.0));
+    assert_eq!(Quantity::Amount(Price::new(50.0, Currency::USD)).units_at(&price), None);
+}
@@ -0,0 +1,334 @@
+//! offline round trip tests of the versioned JSON format
+
+use comdirect_api::error::Error;
+use comdirect_api::types::cost_indication::RawCostIndication;
+use comdirect_api::types::instrument::{Instrument, WarrantType};
+use comdirect_api::types::market_place::MarketPlace;
+use comdirect_api::types::order::RawOrder;
+use comdirect_api::types::position::RawPosition;
+use comdirect_api::types::schema::{self, SchemaType, SCHEMA_VERSION};
+use comdirect_api::types::transaction::RawTransaction;
+use wall_street::order::OrderValidity;
+
+const SINGLE_ORDER: &str = r#"{
+    "depotId": "D0000000000000000000000000000001",
+    "orderId": "O0000000000000000000000000000001",
+    "instrumentId": "I0000000000000000000000000000001",
+    "orderType": "LIMIT",
+    "side": "BUY",
+    "orderStatus": "OPEN",
+    "validityType": "GFD",
+    "limit": {"value": "80.5", "unit": "EUR"},
+    "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+    "quantity": {"value": "10", "unit": "XXX"},
+    "openQuantity": {"value": "10", "unit": "XXX"},
+    "executions": []
+}"#;
+
+const COMBINATION_ORDER: &str = r#"{
+    "depotId": "D0000000000000000000000000000001",
+    "orderId": "O0000000000000000000000000000002",
+    "orderType": "ONE_CANCELS_OTHER",
+    "subOrders": [
+        {
+            "orderId": "O0000000000000000000000000000003",
+            "instrumentId": "I0000000000000000000000000000001",
+            "orderType": "STOP_MARKET",
+            "side": "SELL",
+            "orderStatus": "OPEN",
+            "validityType": "GFD",
+            "triggerLimit": {"value": "70", "unit": "EUR"},
+            "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+            "quantity": {"value": "10", "unit": "XXX"},
+            "executions": []
+        },
+        {
+            "orderId": "O0000000000000000000000000000004",
+            "instrumentId": "I0000000000000000000000000000001",
+            "orderType": "LIMIT",
+            "side": "SELL",
+            "orderStatus": "EXECUTED",
+            "validityType": "GFD",
+            "limit": {"value": "90", "unit": "EUR"},
+            "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+            "quantity": {"value": "10", "unit": "XXX"},
+            "executedQuantity": {"value": "10", "unit": "XXX"},
+            "executions": [
+                {
+                    "executionId": "E0000000000000000000000000000001",
+                    "executionNumber": 1,
+                    "executedQuantity": {"value": "10", "unit": "XXX"},
+                    "executionPrice": {"value": "90", "unit": "EUR"},
+                    "executionTimestamp": "2020-08-04T09:01:02,000000+02"
+                }
+            ]
+        }
+    ]
+}"#;
+
+const POSITION: &str = r#"{
+    "depotId": "D0000000000000000000000000000001",
+    "positionId": "P0000000000000000000000000000001",
+    "wkn": "863186",
+    "custodyType": "CASCADE",
+    "quantity": {"value": "10", "unit": "XXX"},
+    "availableQuantity": {"value": "10", "unit": "XXX"},
+    "currentPrice": {
+        "price": {"value": "82.3", "unit": "EUR"},
+        "priceDateTime": "2020-08-04T17:35:00+02"
+    },
+    "prevDayPrice": {
+        "price": {"value": "81", "unit": "EUR"},
+        "priceDateTime": "2020-08-03T17:35:00+02"
+    },
+    "purchasePrice": {"value": "60", "unit": "EUR"},
+    "currentValue": {"value": "823", "unit": "EUR"},
+    "purchaseValue": {"value": "600", "unit": "EUR"},
+    "profitLossPurchaseAbs": {"value": "223", "unit": "EUR"},
+    "profitLossPrevDayAbs": {"value": "13", "unit": "EUR"},
+    "hedgeability": "HEDGEABLE",
+    "currentPriceDeterminable": true
+}"#;
+
+const TRANSACTION: &str = r#"{
+    "transactionId": "T0000000000000000000000000000001",
+    "bookingStatus": "BOOKED",
+    "bookingDate": "2020-08-05",
+    "businessDate": "2020-08-04",
+    "quantity": {"value": "10", "unit": "XXX"},
+    "instrumentId": "I0000000000000000000000000000001",
+    "executionPrice": {"value": "90", "unit": "EUR"},
+    "transactionValue": {"value": "900", "unit": "EUR"},
+    "transactionDirection": "OUT",
+    "transactionType": "SELL"
+}"#;
+
+const INSTRUMENT: &str = r#"{
+    "instrumentId": "I0000000000000000000000000000001",
+    "wkn": "863186",
+    "isin": "US0079031078",
+    "mnemonic": "AMD",
+    "name": "ADVANCED MICRO DEVICES INC. DL-,01",
+    "shortName": "ADVANCED MIC.DEV. DL-,01",
+    "staticData": {
+        "notation": "XXX",
+        "currency": "USD",
+        "instrumentType": "SHARE",
+        "priipsRelevant": false,
+        "kidAvailable": false,
+        "shippingWaiverRequired": false,
+        "fundRedemptionLimited": false
+    }
+}"#;
+
+const WARRANT: &str = r#"{
+    "instrumentId": "I0000000000000000000000000000002",
+    "wkn": "MA0001",
+    "isin": "DE000MA00011",
+    "mnemonic": "MA0001",
+    "name": "CALL AMD 100 12/21",
+    "staticData": {
+        "currency": "EUR",
+        "instrumentType": "WARRANT"
+    },
+    "derivativeData": {
+        "underlyingInstrument": {
+            "instrumentId": "I0000000000000000000000000000001",
+            "wkn": "863186",
+            "isin": "US0079031078",
+            "name": "ADVANCED MICRO DEVICES INC. DL-,01"
+        },
+        "underlyingPrice": {
+            "price": {"value": "82.3", "unit": "EUR"},
+            "priceDateTime": "2020-08-04T17:35:00+02"
+        },
+        "strikePrice": {"value": "100", "unit": "USD"},
+        "leverage": "4.2",
+        "multiplier": "0.1",
+        "expiryDate": "2021-12-17",
+        "remainingTermInYears": "1.37",
+        "warrantType": "Call"
+    }
+}"#;
+
+const ETF: &str = r#"{
+    "instrumentId": "I0000000000000000000000000000003",
+    "wkn": "A0RPWH",
+    "isin": "IE00B4L5Y983",
+    "mnemonic": "EUNL",
+    "name": "ISHSIII-CORE MSCI WORLD U.ETF",
+    "staticData": {
+        "currency": "EUR",
+        "instrumentType": "ETF"
+    },
+    "fundDistribution": {
+        "currency": "USD",
+        "regularIssueSurcharge": "0",
+        "discountIssueSurcharge": "0",
+        "reducedIssueSurcharge": "0",
+        "investmentCategory": "Aktienfonds",
+        "totalExpenseRatio": "0.2",
+        "rating": {"morningstar": "4", "moodys": null}
+    }
+}"#;
+
+const MARKET_PLACE: &str = r#"{
+    "venueId": "V0000000000000000000000000000001",
+    "name": "Xetra",
+    "sides": ["BUY", "SELL"],
+    "validityTypes": ["GFD", "GTD"],
+    "orderTypes": {
+        "MARKET": {
+            "limitExtensions": [],
+            "tradingRestrictions": ["OAO", "AO", "CAO"]
+        },
+        "LIMIT": {
+            "limitExtensions": ["IOC", "FOK"],
+            "tradingRestrictions": []
+        }
+    }
+}"#;
+
+const COST_INDICATION: &str = r#"{
+    "calculationSuccessful": true,
+    "expectedValue": {"value": "823", "unit": "EUR"},
+    "settlementCurrency": "EUR",
+    "tradingCurrency": "EUR",
+    "reportingCurrency": "EUR",
+    "expectedSettlementCosts": null,
+    "salesCosts": {
+        "type": "V",
+        "sum": null,
+        "sumReportingCurrency": {"value": "9.9", "unit": "EUR"},
+        "costs": [
+            {
+                "type": "E",
+                "amount": null,
+                "amountReportingCurrency": {"value": "9.9", "unit": "EUR"}
+            }
+        ]
+    },
+    "totalCostsAbs": {"value": "9.9", "unit": "EUR"},
+    "totalCostsRel": "1.2",
+    "totalCostsDetail": {
+        "serviceCosts": {"type": "E", "amount": {"value": "9.9", "unit": "EUR"}},
+        "serviceInducement": {"value": "0", "unit": "EUR"},
+        "externalCosts": {"type": "F", "amount": {"value": "0", "unit": "EUR"}},
+        "productCosts": {"type": "P", "amount": {"value": "0", "unit": "EUR"}}
+    },
+    "totalHoldingCosts": {
+        "year1": {"type": "IM_ERSTEN_JAHR", "amount": {"value": "9.9", "unit": "EUR"}},
+        "year2": {"type": "IM_ZWEITEN_JAHR", "amount": {"value": "0", "unit": "EUR"}},
+        "sales": {"type": "IM_JAHR_DER_VERAUESSERUNG", "amount": {"value": "0", "unit": "EUR"}}
+    }
+}"#;
+
+fn assert_round_trip<T: SchemaType + PartialEq + std::fmt::Debug>(response: &str) {
+    let value: T = serde_json::from_str(response).unwrap();
+
+    let json = schema::to_json(&value).unwrap();
+    let envelope: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(envelope["schemaVersion"], SCHEMA_VERSION);
+
+    let round_tripped: T = schema::from_json(&json).unwrap();
+    assert_eq!(value, round_tripped);
+}
+
+#[test]
+fn single_order_round_trip() {
+    assert_round_trip::<RawOrder>(SINGLE_ORDER);
+}
+
+#[test]
+fn combination_order_round_trip() {
+    assert_round_trip::<RawOrder>(COMBINATION_ORDER);
+}
+
+#[test]
+fn good_till_date_order_round_trip() {
+    let order = SINGLE_ORDER.replace(
+        r#""validityType": "GFD","#,
+        r#""validityType": "GTD", "validity": "2020-09-30","#,
+    );
+    assert_round_trip::<RawOrder>(&order);
+
+    match serde_json::from_str::<RawOrder>(&order).unwrap() {
+        RawOrder::SingleOrder(order) => assert_eq!(
+            order.validity().known(),
+            Some(&OrderValidity::TillDate(chrono::NaiveDate::from_ymd(2020, 9, 30)))
+        ),
+        RawOrder::CombinationOrder(_) => panic!("expected a single order"),
+    }
+}
+
+#[test]
+fn order_without_validity() {
+    let order = SINGLE_ORDER.replace(r#""validityType": "GFD","#, "");
+    match serde_json::from_str::<RawOrder>(&order).unwrap() {
+        RawOrder::SingleOrder(order) => assert_eq!(order.validity().known(), Some(&OrderValidity::OneDay)),
+        RawOrder::CombinationOrder(_) => panic!("expected a single order"),
+    }
+}
+
+#[test]
+fn position_round_trip() {
+    assert_round_trip::<RawPosition>(POSITION);
+}
+
+#[test]
+fn transaction_round_trip() {
+    assert_round_trip::<RawTransaction>(TRANSACTION);
+}
+
+#[test]
+fn instrument_round_trip() {
+    assert_round_trip::<Instrument>(INSTRUMENT);
+}
+
+#[test]
+fn warrant_round_trip() {
+    assert_round_trip::<Instrument>(WARRANT);
+
+    let warrant: Instrument = serde_json::from_str(WARRANT).unwrap();
+    let derivative_data = warrant.derivative_data().as_ref().unwrap();
+    assert_eq!(derivative_data.warrant_type(), &Some(WarrantType::Call));
+    assert_eq!(derivative_data.ratio().as_deref(), Some("0.1"));
+}
+
+#[test]
+fn etf_round_trip() {
+    assert_round_trip::<Instrument>(ETF);
+
+    let etf: Instrument = serde_json::from_str(ETF).unwrap();
+    let fund_data = etf.fund_data().as_ref().unwrap();
+    assert_eq!(fund_data.investment_category().as_deref(), Some("Aktienfonds"));
+
+    // the name of the field in 6.2.1
+    let etf: Instrument = serde_json::from_str(&ETF.replace("fundDistribution", "fundsDistribution")).unwrap();
+    assert!(etf.fund_data().is_some());
+}
+
+#[test]
+fn market_place_round_trip() {
+    assert_round_trip::<MarketPlace>(MARKET_PLACE);
+}
+
+#[test]
+fn cost_indication_round_trip() {
+    assert_round_trip::<RawCostIndication>(COST_INDICATION);
+}
+
+#[test]
+fn list_round_trip() {
+    let orders = format!("[{},{}]", SINGLE_ORDER, COMBINATION_ORDER);
+    assert_round_trip::<Vec<RawOrder>>(&orders);
+}
+
+#[test]
+fn unknown_schema_version() {
+    let json = r#"{"schemaVersion":0,"value":{}}"#;
+    assert_eq!(schema::from_json::<RawPosition>(json), Err(Error::UnsupportedSchemaVersion));
+
+    let json = r#"{"schemaVersion":2,"value":{}}"#;
+    assert_eq!(schema::from_json::<RawPosition>(json), Err(Error::UnsupportedSchemaVersion));
+}
@@ -0,0 +1,59 @@
+//! offline tests of the conversion of relative order validities
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use wall_street::order::OrderValidity;
+
+use comdirect_api::error::Error;
+use comdirect_api::types::trading_calendar::{berlin_date, comdirect_validity, is_trading_day, last_trading_day};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd(year, month, day)
+}
+
+#[test]
+fn holidays() {
+    // good friday, easter monday and christmas 2020
+    assert!(!is_trading_day(date(2020, 4, 10)));
+    assert!(!is_trading_day(date(2020, 4, 13)));
+    assert!(!is_trading_day(date(2020, 12, 24)));
+    // saturday
+    assert!(!is_trading_day(date(2020, 8, 8)));
+    assert!(is_trading_day(date(2020, 8, 7)));
+
+    assert_eq!(last_trading_day(date(2020, 12, 27)), date(2020, 12, 23));
+}
+
+#[test]
+fn relative_validities() {
+    // wednesday
+    let today = date(2020, 8, 5);
+
+    assert_eq!(comdirect_validity(OrderValidity::OneDay, today), Ok(OrderValidity::OneDay));
+    assert_eq!(comdirect_validity(OrderValidity::OneWeek, today), Ok(OrderValidity::TillDate(date(2020, 8, 12))));
+    // 2020-09-05 is a saturday
+    assert_eq!(comdirect_validity(OrderValidity::OneMonth, today), Ok(OrderValidity::TillDate(date(2020, 9, 4))));
+    assert_eq!(comdirect_validity(OrderValidity::OneYear, today), Ok(OrderValidity::TillDate(date(2021, 8, 5))));
+}
+
+#[test]
+fn end_of_month() {
+    let today = date(2021, 1, 29);
+    assert_eq!(comdirect_validity(OrderValidity::OneMonth, today), Ok(OrderValidity::TillDate(date(2021, 2, 26))));
+}
+
+#[test]
+fn infinite_validity() {
+    assert_eq!(comdirect_validity(OrderValidity::Infinite, date(2020, 8, 5)), Err(Error::UnsupportedValidity));
+}
+
+#[test]
+fn date_in_berlin() {
+    // CET: 23:30 UTC is 00:30 of the next day
+    assert_eq!(berlin_date(Utc.ymd(2020, 1, 14).and_hms(23, 30, 0)), date(2020, 1, 15));
+    // CEST: 22:30 UTC is 00:30 of the next day
+    assert_eq!(berlin_date(Utc.ymd(2020, 8, 4).and_hms(22, 30, 0)), date(2020, 8, 5));
+    assert_eq!(berlin_date(Utc.ymd(2020, 8, 4).and_hms(21, 30, 0)), date(2020, 8, 4));
+    // summer time ends at 2020-10-25 01:00 UTC
+    assert_eq!(berlin_date(Utc.ymd(2020, 10, 25).and_hms(22, 30, 0)), date(2020, 10, 25));
+    assert_eq!(berlin_date(Utc.ymd(2020, 10, 24).and_hms(22, 30, 0)), date(2020, 10, 25));
+}
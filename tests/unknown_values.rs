@@ -0,0 +1,138 @@
+//! offline tests for enum values, that are unknown to this crate
+
+use comdirect_api::types::instrument::{Instrument, InstrumentType};
+use comdirect_api::types::market_place::MarketPlace;
+use comdirect_api::types::order::{RawOrder, RawSingleOrder};
+use comdirect_api::types::response_value::ResponseValue;
+use wall_street::order::{AuctionType, OrderDirection, OrderStatus, OrderType, OrderTypeExtension, OrderValidity};
+
+#[test]
+fn unknown_instrument_type() {
+    comdirect_api::log_unknown_values(true);
+    let instrument: Instrument = serde_json::from_str(r#"{
+        "instrumentId": "I0000000000000000000000000000001",
+        "wkn": "863186",
+        "isin": "US0079031078",
+        "mnemonic": "AMD",
+        "name": "ADVANCED MICRO DEVICES INC. DL-,01",
+        "staticData": {
+            "currency": "USD",
+            "instrumentType": "CRYPTO_CURRENCY"
+        }
+    }"#).unwrap();
+
+    let instrument_type = instrument.static_data().instrument_type();
+    assert_eq!(instrument_type, &InstrumentType::Unknown("CRYPTO_CURRENCY".to_string()));
+    assert_eq!(serde_json::to_string(instrument_type).unwrap(), r#""CRYPTO_CURRENCY""#);
+}
+
+#[test]
+fn unknown_order_status() {
+    let order: RawOrder = serde_json::from_str(r#"{
+        "orderId": "O0000000000000000000000000000001",
+        "instrumentId": "I0000000000000000000000000000001",
+        "orderType": "LIMIT",
+        "side": "BUY",
+        "orderStatus": "SUSPENDED",
+        "validityType": "GFD",
+        "limit": {"value": "80.5", "unit": "EUR"},
+        "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+        "quantity": {"value": "10", "unit": "XXX"},
+        "executions": []
+    }"#).unwrap();
+
+    match order {
+        RawOrder::SingleOrder(order) => assert_eq!(*order.status(), OrderStatus::Unknown),
+        RawOrder::CombinationOrder(_) => panic!("expected a single order"),
+    }
+}
+
+fn single_order_json(fields: &str) -> String {
+    format!(r#"{{
+        "orderId": "O0000000000000000000000000000001",
+        "instrumentId": "I0000000000000000000000000000001",
+        "orderType": "LIMIT",
+        "side": "BUY",
+        "orderStatus": "OPEN",
+        {}
+        "limit": {{"value": "80.5", "unit": "EUR"}},
+        "creationTimestamp": "2020-08-03T10:22:03,123456+02",
+        "quantity": {{"value": "10", "unit": "XXX"}},
+        "executions": []
+    }}"#, fields)
+}
+
+fn single_order(fields: &str) -> RawSingleOrder {
+    match serde_json::from_str::<RawOrder>(&single_order_json(fields)).unwrap() {
+        RawOrder::SingleOrder(order) => order,
+        RawOrder::CombinationOrder(_) => panic!("expected a single order"),
+    }
+}
+
+#[test]
+fn unknown_validity_type() {
+    for fields in &[r#""validityType": "GTC", "validity": "2020-09-30","#, r#""validityType": "GTC","#] {
+        let order = single_order(fields);
+        assert_eq!(*order.validity(), ResponseValue::Unknown("GTC".to_string()));
+
+        let serialized = serde_json::to_value(&order).unwrap();
+        assert_eq!(serialized["validityType"], "GTC");
+    }
+
+    let without_validity_type = single_order("");
+    assert_eq!(*without_validity_type.validity(), ResponseValue::Known(OrderValidity::OneDay));
+}
+
+#[test]
+fn unknown_order_type() {
+    let json = single_order_json(r#""validityType": "GFD","#).replace(r#""LIMIT""#, r#""ICEBERG""#);
+    let order = match serde_json::from_str::<RawOrder>(&json).unwrap() {
+        RawOrder::SingleOrder(order) => order,
+        RawOrder::CombinationOrder(_) => panic!("expected a single order"),
+    };
+
+    assert_eq!(*order.order_type(), ResponseValue::Unknown("ICEBERG".to_string()));
+    assert_eq!(serde_json::to_value(&order).unwrap()["orderType"], "ICEBERG");
+}
+
+#[test]
+fn unknown_order_type_extension_and_auction_type() {
+    let order = single_order(r#""limitExtension": "GTX", "tradingRestriction": "VOLA","#);
+    assert_eq!(*order.order_type_extension(), ResponseValue::Unknown("GTX".to_string()));
+    assert_eq!(*order.auction(), ResponseValue::Unknown("VOLA".to_string()));
+
+    // the unknown restrictions are kept, when the order is serialized again
+    let json = serde_json::to_value(&order).unwrap();
+    assert_eq!(json["limitExtension"], "GTX");
+    assert_eq!(json["tradingRestriction"], "VOLA");
+
+    let order = single_order(r#""limitExtension": "IOC","#);
+    assert_eq!(*order.order_type_extension(), ResponseValue::Known(OrderTypeExtension::ImmediateOrCancel));
+    assert_eq!(*order.auction(), ResponseValue::Known(AuctionType::All));
+}
+
+#[test]
+fn unknown_market_place_values() {
+    let market_place: MarketPlace = serde_json::from_str(r#"{
+        "venueId": "V0000000000000000000000000000001",
+        "name": "Xetra",
+        "sides": ["BUY", "SELL", "SHORT_SELL"],
+        "validityTypes": ["GFD"],
+        "orderTypes": {
+            "LIMIT": {
+                "limitExtensions": ["IOC", "GTX"],
+                "tradingRestrictions": ["AO", "VOLA"]
+            },
+            "ICEBERG": {
+                "limitExtensions": [],
+                "tradingRestrictions": []
+            }
+        }
+    }"#).unwrap();
+
+    assert_eq!(market_place.order_directions(), &vec![OrderDirection::Buy, OrderDirection::Sell]);
+    assert_eq!(market_place.order_types().len(), 1);
+    let limit = &market_place.order_types()[&OrderType::Limit];
+    assert_eq!(limit.order_type_extensions(), &vec![OrderTypeExtension::ImmediateOrCancel]);
+    assert_eq!(limit.auction_types(), &vec![AuctionType::AuctionOnly]);
+}